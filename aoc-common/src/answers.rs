@@ -0,0 +1,216 @@
+use crate::params::{self, Table};
+use crate::{day_dir, read_lines, read_string, resolve, solver, workspace_dir, InputError};
+use crate::{Solution, Solver};
+use std::fmt;
+use std::path::PathBuf;
+
+/// One line of answers.txt: the answer expected from a day's part on an input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+    /// Too slow to check every time; only run when asked for.
+    pub slow: bool,
+    /// Parameters to solve with instead of the day's defaults, e.g. for the
+    /// smaller limits the examples use.
+    pub params: Table,
+}
+
+impl Expected {
+    pub fn path(&self) -> PathBuf {
+        resolve(day_dir(self.day), &self.input)
+    }
+}
+
+// The same format that parse_answers reads.
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.day, self.part, self.input, self.answer
+        )?;
+        if self.slow {
+            write!(f, " slow")?;
+        }
+        for (name, value) in &self.params {
+            write!(f, " {}={}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Pass(String),
+    Fail(String),
+    /// The input file or the part itself doesn't exist.
+    Missing(String),
+    /// The input couldn't be parsed.
+    Error(String),
+}
+
+fn parse_entry(line: &str) -> Result<Expected, String> {
+    let toks: Vec<&str> = line.split_whitespace().collect();
+    if toks.len() < 4 {
+        return Err(format!(
+            "expected \"day part input answer [slow] [name=value ...]\", got {:?}",
+            line
+        ));
+    }
+    let day = match toks[0].parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => day,
+        _ => return Err(format!("bad day {:?}", toks[0])),
+    };
+    let part = match toks[1].parse::<u8>() {
+        Ok(part) if (1..=2).contains(&part) => part,
+        _ => return Err(format!("bad part {:?}", toks[1])),
+    };
+    let mut slow = false;
+    let mut params = Table::new();
+    for &tok in &toks[4..] {
+        if tok == "slow" {
+            slow = true;
+        } else if tok.contains('=') {
+            let (name, value) = params::parse_assignment(tok)?;
+            params.insert(name, value);
+        } else {
+            return Err(format!("unknown flag {:?}", tok));
+        }
+    }
+    Ok(Expected {
+        day,
+        part,
+        input: toks[2].to_string(),
+        answer: toks[3].to_string(),
+        slow,
+        params,
+    })
+}
+
+/// Blank lines and lines starting with '#' are ignored. Errors carry the
+/// 1-based line number.
+pub fn parse_answers(text: &str) -> Result<Vec<Expected>, (usize, String)> {
+    let mut entries = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        entries.push(parse_entry(line).map_err(|message| (i + 1, message))?);
+    }
+    Ok(entries)
+}
+
+pub fn answers_path() -> PathBuf {
+    workspace_dir().join("answers.txt")
+}
+
+pub fn read_answers() -> Result<Vec<Expected>, InputError> {
+    let path = answers_path();
+    let text = read_string(&path)?;
+    parse_answers(&text).map_err(|(line, message)| InputError::Format {
+        path,
+        line,
+        message,
+    })
+}
+
+pub fn check(day: &dyn Solver, expected: &Expected) -> Outcome {
+    if expected.part > day.parts() {
+        return Outcome::Missing("not implemented".to_string());
+    }
+    let path = expected.path();
+    let lines = match read_lines(&path) {
+        Ok(lines) => lines,
+        Err(err) => return Outcome::Missing(err.to_string()),
+    };
+    if let Err(err) = day.check_params(&expected.params) {
+        return Outcome::Error(format!("bad parameters: {}", err));
+    }
+    let input = path.display().to_string();
+    let solved = params::with(&expected.params, || {
+        day.solve_timed(&input, &lines, &[expected.part])
+    });
+    let actual = match solved {
+        Ok(mut solved) => solved.results.remove(0).answer,
+        Err(err) => return Outcome::Error(err.to_string()),
+    };
+    if actual == expected.answer {
+        Outcome::Pass(actual)
+    } else {
+        Outcome::Fail(actual)
+    }
+}
+
+/// Check every entry for a day, skipping slow ones and parts that aren't
+/// solved yet. Meant to be called from each day's tests.
+pub fn check_answers<S: Solution + 'static>() {
+    let day = solver::<S>();
+    let entries: Vec<_> = read_answers()
+        .unwrap()
+        .into_iter()
+        .filter(|e| e.day == S::DAY && e.part <= S::PARTS && !e.slow)
+        .collect();
+    // A day that hasn't been started has nothing to check yet.
+    assert!(
+        !entries.is_empty() || S::PARTS == 0,
+        "no answers for day {}",
+        S::DAY
+    );
+
+    let mut failures = Vec::new();
+    for e in &entries {
+        match check(day.as_ref(), e) {
+            Outcome::Pass(_) => {}
+            Outcome::Fail(actual) => failures.push(format!("{}: got {}", e, actual)),
+            Outcome::Missing(reason) | Outcome::Error(reason) => {
+                failures.push(format!("{}: {}", e, reason))
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn test_parse_answers() {
+    let text = "# comment\n\n8 2 prelim2.txt 6\n5 2 input.txt 77435348 slow\n";
+    let entries = parse_answers(text).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(
+        entries[0],
+        Expected {
+            day: 8,
+            part: 2,
+            input: "prelim2.txt".to_string(),
+            answer: "6".to_string(),
+            slow: false,
+            params: Table::new(),
+        }
+    );
+    assert!(entries[1].slow);
+    assert_eq!(entries[1].to_string(), "5 2 input.txt 77435348 slow");
+
+    assert_eq!(parse_answers("1 1 prelim.txt").unwrap_err().0, 1);
+    assert_eq!(parse_answers("\n1 3 prelim.txt 5").unwrap_err().0, 2);
+    assert_eq!(parse_answers("26 1 prelim.txt 5").unwrap_err().0, 1);
+    assert!(parse_answers("1 1 prelim.txt 5 fast").is_err());
+
+    let entry = &parse_answers("24 1 prelim.txt 2 pos_min=7 pos_max=27.5").unwrap()[0];
+    assert_eq!(entry.params["pos_min"].as_integer(), Some(7));
+    assert_eq!(entry.params["pos_max"].as_float(), Some(27.5));
+    assert_eq!(
+        entry.to_string(),
+        "24 1 prelim.txt 2 pos_max=27.5 pos_min=7"
+    );
+}
+
+#[test]
+fn test_read_answers() {
+    let entries = read_answers().unwrap();
+    for e in &entries {
+        assert!(e.path().exists(), "{}", e.path().display());
+    }
+}
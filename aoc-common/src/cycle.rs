@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The shape of the sequence start, step(start), step(step(start)), ... once it
+/// repeats: `tail` states that are never seen again, then a loop of `len`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub tail: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    /// The smallest number of steps that ends in the same state as `n` do.
+    pub fn reduce(&self, n: u64) -> usize {
+        let tail = self.tail as u64;
+        if n < tail {
+            n as usize
+        } else {
+            self.tail + ((n - tail) % self.len as u64) as usize
+        }
+    }
+
+    /// The state after `n` steps, e.g. a billion, taking at most tail + len.
+    pub fn nth<T>(&self, start: T, mut step: impl FnMut(&T) -> T, n: u64) -> T {
+        let mut state = start;
+        for _ in 0..self.reduce(n) {
+            state = step(&state);
+        }
+        state
+    }
+}
+
+/// Floyd's tortoise and hare, which only ever holds two states.
+pub fn floyd<T: Clone + PartialEq>(start: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // The hare is now a multiple of the loop length ahead, so walking both at
+    // the same speed from the start and the meeting point lines them up where
+    // the loop begins.
+    let mut tail = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+
+    let mut len = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        len += 1;
+    }
+    Cycle { tail, len }
+}
+
+/// Brent's algorithm, which also holds two states but takes fewer steps than
+/// Floyd's, and finds the loop length first.
+pub fn brent<T: Clone + PartialEq>(start: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    let mut power = 1;
+    let mut len = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        hare = step(&hare);
+        len += 1;
+    }
+
+    // With the hare len steps ahead, the two meet where the loop begins.
+    let mut tail = 0;
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..len {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+    Cycle { tail, len }
+}
+
+/// Every state up to the point where the sequence repeats, for when they're
+/// small enough to keep. `step` is called exactly tail + len times.
+#[derive(Clone, Debug)]
+pub struct History<T> {
+    pub cycle: Cycle,
+    pub states: Vec<T>,
+}
+
+impl<T> History<T> {
+    pub fn nth(&self, n: u64) -> &T {
+        &self.states[self.cycle.reduce(n)]
+    }
+}
+
+pub fn hashed<T: Clone + Eq + Hash>(start: T, mut step: impl FnMut(&T) -> T) -> History<T> {
+    let mut seen = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+    loop {
+        let next = step(states.last().unwrap());
+        if let Some(&tail) = seen.get(&next) {
+            let len = states.len() - tail;
+            return History {
+                cycle: Cycle { tail, len },
+                states,
+            };
+        }
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+}
+
+#[test]
+fn test_cycle() {
+    // A tail of 3 into a loop of 4.
+    let step = |&x: &u32| if x < 6 { x + 1 } else { 3 };
+    let expected = Cycle { tail: 3, len: 4 };
+    assert_eq!(floyd(0, step), expected);
+    assert_eq!(brent(0, step), expected);
+    let history = hashed(0, step);
+    assert_eq!(history.cycle, expected);
+    assert_eq!(history.states, [0, 1, 2, 3, 4, 5, 6]);
+
+    assert_eq!(expected.reduce(2), 2);
+    assert_eq!(expected.reduce(7), 3);
+    assert_eq!(expected.reduce(1_000_000_000), 4);
+    assert_eq!(*history.nth(1_000_000_000), 4);
+    assert_eq!(expected.nth(0, step, 1_000_000_000), 4);
+
+    // A pure loop, and a fixed point.
+    assert_eq!(brent(5, |&x: &u32| (x + 1) % 9), Cycle { tail: 0, len: 9 });
+    assert_eq!(floyd(5, |&x: &u32| x.min(2)), Cycle { tail: 1, len: 1 });
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn detectors_agree(seed in 0u64..1000, modulus in 1u64..500, c in 0u64..500) {
+        let step = |&x: &u64| (x * x + c) % modulus;
+        let history = hashed(seed % modulus, step);
+        prop_assert_eq!(floyd(seed % modulus, step), history.cycle);
+        prop_assert_eq!(brent(seed % modulus, step), history.cycle);
+
+        let mut x = seed % modulus;
+        for n in 0..(2 * history.states.len() as u64) {
+            prop_assert_eq!(*history.nth(n), x);
+            x = step(&x);
+        }
+    }
+}
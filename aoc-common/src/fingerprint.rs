@@ -0,0 +1,290 @@
+use crate::Solver;
+use regex::Regex;
+use std::fmt;
+use tracing::debug;
+
+/// The rough shape of a day's input, cheap enough to check before parsing, so
+/// that another day's input or a partial copy-paste gets a plain message rather
+/// than a parse error halfway down. Parts match runs of lines in order, which
+/// `to_lines` has already stripped of trailing blank lines. An empty
+/// fingerprint matches anything.
+#[derive(Clone, Debug, Default)]
+pub struct Fingerprint {
+    parts: Vec<Part>,
+}
+
+#[derive(Clone, Debug)]
+enum Part {
+    Line(Regex),
+    Lines(Regex),
+    Blank,
+    Grid {
+        row: Regex,
+        containing: Option<&'static str>,
+    },
+    Repeat {
+        min: usize,
+        each: Fingerprint,
+    },
+}
+
+/// Where the input stopped fitting. `truncated` means it looked right up to
+/// its end but stopped short, e.g. part way through a line or a section.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mismatch {
+    pub line: usize,
+    pub truncated: bool,
+    pub expected: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.line, self.truncated) {
+            (0, _) => write!(f, "input is empty"),
+            (line, true) => write!(f, "input truncated at line {}", line),
+            (line, false) => write!(f, "line {}: expected {}", line, self.expected),
+        }
+    }
+}
+
+// The patterns match whole lines.
+fn whole(pattern: &str) -> Regex {
+    Regex::new(&format!("^(?:{})$", pattern)).expect("bad fingerprint pattern")
+}
+
+// The pattern as it was given, without what `whole` added.
+fn shown(re: &Regex) -> &str {
+    let pattern = re.as_str();
+    &pattern[4..pattern.len() - 2]
+}
+
+fn mismatch(line: usize, truncated: bool, expected: impl Into<String>) -> Mismatch {
+    Mismatch {
+        line,
+        truncated,
+        expected: expected.into(),
+    }
+}
+
+impl Fingerprint {
+    pub fn new() -> Self {
+        Fingerprint::default()
+    }
+
+    /// Exactly one line matching `pattern`.
+    pub fn line(mut self, pattern: &str) -> Self {
+        self.parts.push(Part::Line(whole(pattern)));
+        self
+    }
+
+    /// One or more lines matching `pattern`, up to a blank line or the end.
+    pub fn lines(mut self, pattern: &str) -> Self {
+        self.parts.push(Part::Lines(whole(pattern)));
+        self
+    }
+
+    pub fn blank(mut self) -> Self {
+        self.parts.push(Part::Blank);
+        self
+    }
+
+    /// Rows of the same width, each cell matching `cell`, e.g. "[.#]".
+    pub fn grid(mut self, cell: &str) -> Self {
+        self.parts.push(Part::Grid {
+            row: whole(&format!("(?:{})+", cell)),
+            containing: None,
+        });
+        self
+    }
+
+    /// The grid before this has at least one of `chars` somewhere in it.
+    pub fn containing(mut self, chars: &'static str) -> Self {
+        match self.parts.last_mut() {
+            Some(Part::Grid { containing, .. }) => *containing = Some(chars),
+            _ => panic!("containing() has to follow grid()"),
+        }
+        self
+    }
+
+    /// `each` over and over to the end of the input, at least `min` times.
+    pub fn repeat(mut self, min: usize, each: Fingerprint) -> Self {
+        self.parts.push(Part::Repeat { min, each });
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    pub fn check(&self, lines: &[String]) -> Result<(), Mismatch> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        let at = self.matches(lines, 0)?;
+        if at < lines.len() {
+            return Err(mismatch(at + 1, false, "the end of the input"));
+        }
+        Ok(())
+    }
+
+    // Where the next part starts after matching these from line `at`.
+    fn matches(&self, lines: &[String], mut at: usize) -> Result<usize, Mismatch> {
+        for part in &self.parts {
+            at = part.matches(lines, at)?;
+        }
+        Ok(at)
+    }
+}
+
+impl Part {
+    fn matches(&self, lines: &[String], at: usize) -> Result<usize, Mismatch> {
+        let last = lines.len();
+        if at == last {
+            return Err(mismatch(last, true, "more input"));
+        }
+        let starts_block = matches!(self, Part::Lines(_) | Part::Grid { .. });
+        if starts_block && lines[at].is_empty() {
+            return Err(mismatch(at + 1, false, "more lines"));
+        }
+        match self {
+            Part::Line(re) => {
+                if !re.is_match(&lines[at]) {
+                    return Err(mismatch(at + 1, at > 0 && at + 1 == last, shown(re)));
+                }
+                Ok(at + 1)
+            }
+            Part::Blank => {
+                if !lines[at].is_empty() {
+                    return Err(mismatch(at + 1, false, "a blank line"));
+                }
+                Ok(at + 1)
+            }
+            Part::Lines(re) => {
+                let block = block(lines, at);
+                match block.iter().position(|l| !re.is_match(l)) {
+                    None => Ok(at + block.len()),
+                    // Only the last line can have been cut short.
+                    Some(i) => Err(mismatch(at + i + 1, i > 0 && at + i + 1 == last, shown(re))),
+                }
+            }
+            Part::Grid { row, containing } => {
+                let block = block(lines, at);
+                let width = block[0].chars().count();
+                for (i, line) in block.iter().enumerate() {
+                    let cells = line.chars().count();
+                    if cells != width {
+                        let truncated = i > 0 && at + i + 1 == last && cells < width;
+                        let expected = format!("a row of {} cells", width);
+                        return Err(mismatch(at + i + 1, truncated, expected));
+                    }
+                    if !row.is_match(line) {
+                        return Err(mismatch(at + i + 1, false, shown(row)));
+                    }
+                }
+                if let Some(chars) = containing {
+                    if !block.iter().any(|l| l.contains(|c| chars.contains(c))) {
+                        let expected = format!("a grid with one of {:?} in it", chars);
+                        return Err(mismatch(at + 1, false, expected));
+                    }
+                }
+                Ok(at + block.len())
+            }
+            Part::Repeat { min, each } => {
+                let mut at = at;
+                let mut count = 0;
+                while at < last {
+                    let next = each.matches(lines, at)?;
+                    assert!(next > at, "repeat() needs a fingerprint that takes lines");
+                    at = next;
+                    count += 1;
+                }
+                if count < *min {
+                    return Err(mismatch(last, true, "more sections"));
+                }
+                Ok(at)
+            }
+        }
+    }
+}
+
+// The lines from `at` up to the next blank line.
+fn block(lines: &[String], at: usize) -> &[String] {
+    let len = lines[at..]
+        .iter()
+        .position(|l| l.is_empty())
+        .unwrap_or(lines.len() - at);
+    &lines[at..at + len]
+}
+
+/// Check a day's input against its fingerprint before solving. Input that
+/// fits another day's fingerprint instead is reported as that day's, and input
+/// that stops short as truncated. Anything else is left to the parser, which
+/// can say exactly what's wrong with it.
+pub fn screen_input(
+    days: &[Box<dyn Solver>],
+    day: &dyn Solver,
+    lines: &[String],
+) -> Result<(), String> {
+    let Err(mismatch) = day.fingerprint().check(lines) else {
+        return Ok(());
+    };
+    debug!(target: "fingerprint", "day {}: {}", day.day(), mismatch);
+    let others: Vec<String> = days
+        .iter()
+        .filter(|other| other.day() != day.day())
+        .filter(|other| {
+            let fingerprint = other.fingerprint();
+            !fingerprint.is_empty() && fingerprint.check(lines).is_ok()
+        })
+        .map(|other| other.day().to_string())
+        .collect();
+    if !others.is_empty() {
+        Err(format!("this looks like day {} input", others.join(" or ")))
+    } else if mismatch.truncated || mismatch.line == 0 {
+        Err(mismatch.to_string())
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_fingerprint() {
+    let lines = |text: &str| crate::to_lines(text);
+    let network = Fingerprint::new()
+        .line("[LR]+")
+        .blank()
+        .lines(r"\w{3} = \(\w{3}, \w{3}\)");
+    assert_eq!(network.check(&lines("LR\n\nAAA = (BBB, CCC)\n\n")), Ok(()));
+    assert_eq!(
+        network.check(&lines("LR\n\nAAA = (BBB, CCC)\nBBB = (AA")),
+        Err(mismatch(4, true, r"\w{3} = \(\w{3}, \w{3}\)"))
+    );
+    assert_eq!(network.check(&lines("LR\n")).unwrap_err().line, 1);
+    assert!(network.check(&lines("LR\n")).unwrap_err().truncated);
+    assert!(
+        !network
+            .check(&lines("LX\n\nAAA = (BBB, CCC)"))
+            .unwrap_err()
+            .truncated
+    );
+    assert_eq!(
+        network.check(&[]).unwrap_err().to_string(),
+        "input is empty"
+    );
+
+    let mirrors = Fingerprint::new()
+        .grid("[.#]")
+        .repeat(1, Fingerprint::new().blank().grid("[.#]"));
+    assert!(mirrors.check(&lines("#.\n.#\n\n###\n...")).is_ok());
+    let err = mirrors.check(&lines("#.\n.#\n\n###\n..")).unwrap_err();
+    assert_eq!(err.to_string(), "input truncated at line 5");
+    let err = mirrors.check(&lines("#.\n.#\n")).unwrap_err();
+    assert_eq!(err.to_string(), "input truncated at line 2");
+    let err = mirrors.check(&lines("#.\n.#.\n\n#")).unwrap_err();
+    assert_eq!(err.to_string(), "line 2: expected a row of 2 cells");
+
+    let pipes = Fingerprint::new().grid("[-|LJ7F.S]").containing("S");
+    assert!(pipes.check(&lines(".S\n-J")).is_ok());
+    assert!(pipes.check(&lines("..\n-J")).is_err());
+    assert!(Fingerprint::new().check(&lines("anything")).is_ok());
+}
@@ -0,0 +1,395 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Anything that can say where you can go from a node, and at what cost.
+/// Searches only need this much, so the nodes can be generated on the fly.
+pub trait Graph {
+    type Node: Copy + Eq + Hash;
+
+    fn neighbours(&self, node: Self::Node) -> impl Iterator<Item = (Self::Node, u64)>;
+}
+
+/// A graph whose nodes can all be listed, for the whole graph algorithms.
+pub trait FiniteGraph: Graph {
+    fn nodes(&self) -> impl Iterator<Item = Self::Node>;
+}
+
+/// A graph given by a closure returning each node's neighbours and costs.
+pub struct FnGraph<N, F>(F, PhantomData<fn(N)>);
+
+pub fn from_fn<N, I, F>(f: F) -> FnGraph<N, F>
+where
+    F: Fn(N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    FnGraph(f, PhantomData)
+}
+
+impl<N, I, F> Graph for FnGraph<N, F>
+where
+    N: Copy + Eq + Hash,
+    F: Fn(N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    type Node = N;
+
+    fn neighbours(&self, node: N) -> impl Iterator<Item = (N, u64)> {
+        (self.0)(node).into_iter()
+    }
+}
+
+/// Hands out a dense id for each distinct name, in order of first sight.
+#[derive(Clone, Debug, Default)]
+pub struct Interner {
+    ids: HashMap<String, usize>,
+    names: Vec<String>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intern(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.ids.insert(name.to_string(), id);
+        self.names.push(name.to_string());
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+/// Weighted adjacency lists over named nodes.
+#[derive(Clone, Debug, Default)]
+pub struct AdjList {
+    pub names: Interner,
+    edges: Vec<Vec<(usize, u64)>>,
+}
+
+impl AdjList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn node(&mut self, name: &str) -> usize {
+        let id = self.names.intern(name);
+        if id == self.edges.len() {
+            self.edges.push(Vec::new());
+        }
+        id
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, cost: u64) {
+        self.edges[from].push((to, cost));
+    }
+
+    pub fn add_undirected(&mut self, a: usize, b: usize, cost: u64) {
+        self.add_edge(a, b, cost);
+        self.add_edge(b, a, cost);
+    }
+
+    pub fn edges(&self, node: usize) -> &[(usize, u64)] {
+        &self.edges[node]
+    }
+
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+}
+
+impl Graph for AdjList {
+    type Node = usize;
+
+    fn neighbours(&self, node: usize) -> impl Iterator<Item = (usize, u64)> {
+        self.edges[node].iter().copied()
+    }
+}
+
+impl FiniteGraph for AdjList {
+    fn nodes(&self) -> impl Iterator<Item = usize> {
+        0..self.edges.len()
+    }
+}
+
+/// The total cost of a path along with its nodes, start and end included.
+pub type CostPath<N> = (u64, Vec<N>);
+
+/// Number of edges to every node reachable from start, ignoring costs.
+pub fn distances<G: Graph>(graph: &G, start: G::Node) -> HashMap<G::Node, usize> {
+    let mut dist = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let d = dist[&node];
+        for (next, _) in graph.neighbours(node) {
+            dist.entry(next).or_insert_with(|| {
+                queue.push_back(next);
+                d + 1
+            });
+        }
+    }
+    dist
+}
+
+pub fn reachable<G: Graph>(graph: &G, start: G::Node) -> HashSet<G::Node> {
+    distances(graph, start).into_keys().collect()
+}
+
+// Follow parent links back from `end` to the start of a search.
+fn trace_back<N: Copy + Eq + Hash>(parents: &HashMap<N, N>, end: N) -> Vec<N> {
+    let mut path = vec![end];
+    while let Some(&prev) = parents.get(path.last().unwrap()) {
+        path.push(prev);
+    }
+    path.reverse();
+    path
+}
+
+/// Fewest edges from start to a goal node, ignoring costs.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut goal: impl FnMut(G::Node) -> bool,
+) -> Option<Vec<G::Node>> {
+    let mut parents = HashMap::new();
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if goal(node) {
+            return Some(trace_back(&parents, node));
+        }
+        for (next, _) in graph.neighbours(node) {
+            if seen.insert(next) {
+                parents.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    goal: impl FnMut(G::Node) -> bool,
+) -> Option<CostPath<G::Node>> {
+    astar(graph, start, goal, |_| 0)
+}
+
+/// Cheapest path from start to a goal node. The heuristic must never
+/// overestimate the remaining cost.
+pub fn astar<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut goal: impl FnMut(G::Node) -> bool,
+    mut heuristic: impl FnMut(G::Node) -> u64,
+) -> Option<CostPath<G::Node>> {
+    let mut best = HashMap::from([(start, 0)]);
+    let mut parents = HashMap::new();
+    // Nodes go in the heap by index, so they don't need to be Ord.
+    let mut nodes = vec![start];
+    let mut heap = BinaryHeap::from([(Reverse(heuristic(start)), 0, 0)]);
+    while let Some((_, cost, i)) = heap.pop() {
+        let node = nodes[i];
+        if cost > best[&node] {
+            continue;
+        }
+        if goal(node) {
+            return Some((cost, trace_back(&parents, node)));
+        }
+        for (next, step) in graph.neighbours(node) {
+            let next_cost = cost + step;
+            if best.get(&next).is_none_or(|&c| next_cost < c) {
+                best.insert(next, next_cost);
+                parents.insert(next, node);
+                nodes.push(next);
+                let estimate = next_cost + heuristic(next);
+                heap.push((Reverse(estimate), next_cost, nodes.len() - 1));
+            }
+        }
+    }
+    None
+}
+
+/// Groups of nodes connected to each other, for graphs whose edges go both
+/// ways.
+pub fn components<G: FiniteGraph>(graph: &G) -> Vec<Vec<G::Node>> {
+    let mut seen = HashSet::new();
+    let mut groups = Vec::new();
+    for node in graph.nodes() {
+        if !seen.insert(node) {
+            continue;
+        }
+        let mut group = vec![node];
+        let mut i = 0;
+        while i < group.len() {
+            for (next, _) in graph.neighbours(group[i]) {
+                if seen.insert(next) {
+                    group.push(next);
+                }
+            }
+            i += 1;
+        }
+        groups.push(group);
+    }
+    groups
+}
+
+/// Every node ordered so that edges only go forwards, or the nodes of a cycle
+/// (in order, first node repeated at the end) if there isn't such an order.
+pub fn toposort<G: FiniteGraph>(graph: &G) -> Result<Vec<G::Node>, Vec<G::Node>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        Open,
+        Done,
+    }
+
+    let mut state = HashMap::new();
+    let mut order = Vec::new();
+    for root in graph.nodes() {
+        if state.contains_key(&root) {
+            continue;
+        }
+        // An explicit stack so that long chains can't overflow.
+        let mut stack = vec![(root, graph.neighbours(root).collect::<Vec<_>>())];
+        state.insert(root, State::Open);
+        while let Some((node, next)) = stack.last_mut() {
+            let node = *node;
+            match next.pop() {
+                Some((next, _)) => match state.get(&next) {
+                    None => {
+                        state.insert(next, State::Open);
+                        stack.push((next, graph.neighbours(next).collect()));
+                    }
+                    Some(State::Open) => {
+                        let from = stack.iter().position(|&(n, _)| n == next).unwrap();
+                        let mut cycle: Vec<_> = stack[from..].iter().map(|&(n, _)| n).collect();
+                        cycle.push(next);
+                        return Err(cycle);
+                    }
+                    Some(State::Done) => {}
+                },
+                None => {
+                    state.insert(node, State::Done);
+                    order.push(node);
+                    stack.pop();
+                }
+            }
+        }
+    }
+    order.reverse();
+    Ok(order)
+}
+
+pub fn find_cycle<G: FiniteGraph>(graph: &G) -> Option<Vec<G::Node>> {
+    toposort(graph).err()
+}
+
+/// The most expensive path from start to goal in a graph without cycles. A
+/// cycle is returned as the error, since there'd be no longest path.
+pub fn longest_path<G: FiniteGraph>(
+    graph: &G,
+    start: G::Node,
+    goal: G::Node,
+) -> Result<Option<CostPath<G::Node>>, Vec<G::Node>> {
+    let order = toposort(graph)?;
+    let mut best = HashMap::from([(start, 0)]);
+    let mut parents = HashMap::new();
+    for node in order {
+        let Some(&cost) = best.get(&node) else {
+            continue;
+        };
+        for (next, step) in graph.neighbours(node) {
+            if best.get(&next).is_none_or(|&c| cost + step > c) {
+                best.insert(next, cost + step);
+                parents.insert(next, node);
+            }
+        }
+    }
+    Ok(best
+        .get(&goal)
+        .map(|&cost| (cost, trace_back(&parents, goal))))
+}
+
+#[cfg(test)]
+fn sample() -> AdjList {
+    // a -> b -> d, a -> c -> d, with c the cheap way round.
+    let mut graph = AdjList::new();
+    for (from, to, cost) in [("a", "b", 5), ("a", "c", 1), ("b", "d", 1), ("c", "d", 2)] {
+        let (from, to) = (graph.node(from), graph.node(to));
+        graph.add_edge(from, to, cost);
+    }
+    graph
+}
+
+#[test]
+fn test_interner() {
+    let mut names = Interner::new();
+    assert_eq!(names.intern("jqt"), 0);
+    assert_eq!(names.intern("rhn"), 1);
+    assert_eq!(names.intern("jqt"), 0);
+    assert_eq!(names.id("rhn"), Some(1));
+    assert_eq!(names.name(1), "rhn");
+    assert_eq!(names.len(), 2);
+}
+
+#[test]
+fn test_searches() {
+    let graph = sample();
+    let [a, b, c, d] = ["a", "b", "c", "d"].map(|n| graph.names.id(n).unwrap());
+    assert_eq!(distances(&graph, a)[&d], 2);
+    assert_eq!(bfs(&graph, a, |n| n == d).unwrap().len(), 3);
+    assert_eq!(dijkstra(&graph, a, |n| n == d), Some((3, vec![a, c, d])));
+    assert_eq!(dijkstra(&graph, b, |n| n == a), None);
+    assert_eq!(longest_path(&graph, a, d), Ok(Some((6, vec![a, b, d]))));
+
+    // A closure graph on a number line: +1 costs 1, *2 costs 1.
+    let line = from_fn(|n: u64| [(n + 1, 1), (n * 2, 1)]);
+    let (cost, _) = astar(&line, 1, |n| n == 10, |n| 10u64.saturating_sub(n).min(1)).unwrap();
+    assert_eq!(cost, 4);
+}
+
+#[test]
+fn test_whole_graph() {
+    let mut graph = sample();
+    let order = toposort(&graph).unwrap();
+    assert_eq!(order.first(), graph.names.id("a").as_ref());
+    assert_eq!(order.last(), graph.names.id("d").as_ref());
+    assert_eq!(find_cycle(&graph), None);
+
+    let (d, b) = (graph.node("d"), graph.node("b"));
+    graph.add_edge(d, b, 1);
+    assert_eq!(find_cycle(&graph), Some(vec![d, b, d]));
+    assert!(longest_path(&graph, 0, d).is_err());
+
+    let mut graph = AdjList::new();
+    for (x, y) in [("a", "b"), ("c", "d"), ("d", "e")] {
+        let (x, y) = (graph.node(x), graph.node(y));
+        graph.add_undirected(x, y, 1);
+    }
+    let sizes: Vec<_> = components(&graph).iter().map(|g| g.len()).collect();
+    assert_eq!(sizes, [2, 3]);
+}
@@ -0,0 +1,315 @@
+use crate::{numbered, Line, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Every grid position is (x, y): x is the column, y is the row, and (0, 0) is
+/// the top left corner of the input.
+pub type Pos = (usize, usize);
+
+const DIRS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const DIRS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A rectangular grid stored row by row.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Panics if the rows aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+        assert!(rows.iter().all(|r| r.len() == width), "ragged grid rows");
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Build a grid from lines of text, one cell per character. `cell` returns
+    /// None for characters that aren't allowed.
+    pub fn parse(
+        lines: &[String],
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        Self::parse_lines(numbered(lines), cell)
+    }
+
+    /// As parse, for a block taken from a larger input so that errors still
+    /// point at the right line.
+    pub fn parse_lines<'a>(
+        lines: impl IntoIterator<Item = Line<'a>>,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in lines {
+            let mut count = 0;
+            for (i, c) in line.text.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        let token = &line.text[i..i + c.len_utf8()];
+                        return Err(line.error(token, format!("unexpected character {:?}", c)));
+                    }
+                }
+                count += 1;
+            }
+            if *width.get_or_insert(count) != count {
+                let message = format!("expected {} cells, found {}", width.unwrap(), count);
+                return Err(line.error(line.text, message));
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseError::missing(0, "empty grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// Up, right, down, left, leaving out any that fall off the edge.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.offsets(pos, &DIRS4)
+    }
+
+    /// Clockwise from the top left, leaving out any that fall off the edge.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.offsets(pos, &DIRS8)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        (x, y): Pos,
+        dirs: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        dirs.iter().filter_map(move |&(dx, dy)| {
+            let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            self.contains(pos).then_some(pos)
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of range", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, v)| pred(v)).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Swap rows and columns, so that (x, y) moves to (y, x).
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        let h = self.height;
+        Grid::from_fn(h, self.width, |(x, y)| self[(y, h - 1 - x)].clone())
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        let w = self.width;
+        Grid::from_fn(self.height, w, |(x, y)| self[(w - 1 - y, x)].clone())
+    }
+
+    /// Mirror left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let w = self.width;
+        Grid::from_fn(w, self.height, |(x, y)| self[(w - 1 - x, y)].clone())
+    }
+
+    /// Mirror top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let h = self.height;
+        Grid::from_fn(self.width, h, |(x, y)| self[(x, h - 1 - y)].clone())
+    }
+}
+
+impl Grid<char> {
+    /// Any character is a cell, as for most map style puzzles.
+    pub fn parse_chars(lines: &[String]) -> Result<Self, ParseError> {
+        Self::parse(lines, Some)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Pos) -> &T {
+        assert!(
+            self.contains((x, y)),
+            "({}, {}) is outside {}x{} grid",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Pos) -> &mut T {
+        assert!(
+            self.contains((x, y)),
+            "({}, {}) is outside {}x{} grid",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+// One line per row with no separators, so a Grid<char> prints as it was read.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn sample() -> Grid<char> {
+    let lines = crate::to_lines("abc\ndef\n");
+    Grid::parse_chars(&lines).unwrap()
+}
+
+#[test]
+fn test_parse() {
+    let grid = sample();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 1)], 'f');
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    assert_eq!(grid.column(1).collect::<String>(), "be");
+    assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+    assert_eq!(grid.to_string(), "abc\ndef\n");
+
+    let lines = crate::to_lines("#.\n.x\n");
+    let err = Grid::parse(&lines, |c| (c != 'x').then_some(c == '#')).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 2));
+    let lines = crate::to_lines("#.\n.\n");
+    let err = Grid::parse_chars(&lines).unwrap_err();
+    assert_eq!(err.message, "expected 2 cells, found 1");
+    assert!(Grid::parse_chars(&[]).is_err());
+    let lines = crate::to_lines("#.\n.#\n\n");
+    assert_eq!(Grid::parse_chars(&lines).unwrap().height(), 2);
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = sample();
+    let n: Vec<_> = grid.neighbours4((0, 0)).collect();
+    assert_eq!(n, [(1, 0), (0, 1)]);
+    let n: Vec<_> = grid.neighbours8((1, 0)).collect();
+    assert_eq!(n, [(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]);
+}
+
+#[test]
+fn test_transforms() {
+    let grid = sample();
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+    assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+    assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+    assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+}
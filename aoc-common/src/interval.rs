@@ -0,0 +1,331 @@
+use std::cmp::{max, min};
+
+/// The half-open range start..end. Anything with end <= start is empty.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        Interval { start, end }
+    }
+
+    pub fn from_len(start: i64, len: i64) -> Self {
+        Interval::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn len(&self) -> i64 {
+        max(self.end - self.start, 0)
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        self.start <= x && x < self.end
+    }
+
+    pub fn intersect(&self, other: &Interval) -> Option<Interval> {
+        let both = Interval::new(max(self.start, other.start), min(self.end, other.end));
+        (!both.is_empty()).then_some(both)
+    }
+
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+/// A set of points kept as sorted, disjoint, non-touching intervals.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IntervalSet {
+    ranges: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        // Everything overlapping or touching the new interval is merged in.
+        let lo = self.ranges.partition_point(|r| r.end < interval.start);
+        let hi = self.ranges.partition_point(|r| r.start <= interval.end);
+        let mut merged = interval;
+        if lo < hi {
+            merged.start = min(merged.start, self.ranges[lo].start);
+            merged.end = max(merged.end, self.ranges[hi - 1].end);
+        }
+        self.ranges.splice(lo..hi, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut set = self.clone();
+        other.iter().for_each(|&r| set.insert(r));
+        set
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (self.ranges[i], other.ranges[j]);
+            if let Some(both) = a.intersect(&b) {
+                ranges.push(both);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// The points of self that aren't in other.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let mut holes = other.ranges.iter().peekable();
+        for &range in &self.ranges {
+            let mut start = range.start;
+            while let Some(hole) = holes.peek() {
+                if hole.end <= start {
+                    holes.next();
+                    continue;
+                }
+                if hole.start >= range.end {
+                    break;
+                }
+                if hole.start > start {
+                    ranges.push(Interval::new(start, hole.start));
+                }
+                start = hole.end;
+                if hole.end >= range.end {
+                    break;
+                }
+                holes.next();
+            }
+            if start < range.end {
+                ranges.push(Interval::new(start, range.end));
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn shift(&self, offset: i64) -> IntervalSet {
+        IntervalSet {
+            ranges: self.ranges.iter().map(|r| r.shift(offset)).collect(),
+        }
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= x);
+        self.ranges.get(i).is_some_and(|r| r.contains(x))
+    }
+
+    /// How many points are in the set.
+    pub fn len(&self) -> i64 {
+        self.ranges.iter().map(|r| r.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.ranges.iter()
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        iter.into_iter().for_each(|r| set.insert(r));
+        set
+    }
+}
+
+/// A piecewise shift: points in each source interval move by its offset, and
+/// points outside all of them stay where they are.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RangeMap {
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl RangeMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pieces can't overlap, so the one already there is the error.
+    pub fn insert(&mut self, source: Interval, offset: i64) -> Result<(), Interval> {
+        let i = self.pieces.partition_point(|(r, _)| r.end <= source.start);
+        if let Some(&(existing, _)) = self.pieces.get(i) {
+            if existing.intersect(&source).is_some() {
+                return Err(existing);
+            }
+        }
+        if !source.is_empty() {
+            self.pieces.insert(i, (source, offset));
+        }
+        Ok(())
+    }
+
+    pub fn map(&self, x: i64) -> i64 {
+        let i = self.pieces.partition_point(|(r, _)| r.end <= x);
+        match self.pieces.get(i) {
+            Some(&(r, offset)) if r.contains(x) => x + offset,
+            _ => x,
+        }
+    }
+
+    /// Cut an interval wherever a piece starts or stops, giving each part with
+    /// the offset that applies to it, in order.
+    pub fn split(&self, interval: Interval) -> Vec<(Interval, i64)> {
+        let mut parts = Vec::new();
+        if interval.is_empty() {
+            return parts;
+        }
+        let mut start = interval.start;
+        let first = self.pieces.partition_point(|(r, _)| r.end <= start);
+        for &(piece, offset) in &self.pieces[first..] {
+            if piece.start >= interval.end {
+                break;
+            }
+            if piece.start > start {
+                parts.push((Interval::new(start, piece.start), 0));
+            }
+            let both = piece.intersect(&interval).unwrap();
+            parts.push((both, offset));
+            start = both.end;
+        }
+        if start < interval.end {
+            parts.push((Interval::new(start, interval.end), 0));
+        }
+        parts
+    }
+
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        set.iter()
+            .flat_map(|&r| self.split(r))
+            .map(|(r, offset)| r.shift(offset))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+// Small coordinates so that intervals overlap often.
+#[cfg(test)]
+fn interval() -> impl Strategy<Value = Interval> {
+    (-20i64..20, 0i64..10).prop_map(|(start, len)| Interval::from_len(start, len))
+}
+
+#[cfg(test)]
+fn set() -> impl Strategy<Value = IntervalSet> {
+    prop::collection::vec(interval(), 0..6).prop_map(IntervalSet::from_iter)
+}
+
+#[cfg(test)]
+fn range_map() -> impl Strategy<Value = RangeMap> {
+    prop::collection::vec((interval(), -30i64..30), 0..6).prop_map(|pieces| {
+        let mut map = RangeMap::new();
+        for (r, offset) in pieces {
+            let _ = map.insert(r, offset);
+        }
+        map
+    })
+}
+
+#[cfg(test)]
+fn points(set: &IntervalSet) -> Vec<i64> {
+    (-80..80).filter(|&x| set.contains(x)).collect()
+}
+
+#[cfg(test)]
+fn well_formed(set: &IntervalSet) -> bool {
+    set.ranges.iter().all(|r| !r.is_empty()) && set.ranges.windows(2).all(|w| w[0].end < w[1].start)
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn set_operations(a in set(), b in set()) {
+        for (c, op) in [
+            (a.union(&b), (|x, y| x || y) as fn(bool, bool) -> bool),
+            (a.intersection(&b), |x, y| x && y),
+            (a.difference(&b), |x, y| x && !y),
+        ] {
+            prop_assert!(well_formed(&c));
+            for x in -40..40 {
+                prop_assert_eq!(c.contains(x), op(a.contains(x), b.contains(x)));
+            }
+        }
+        prop_assert_eq!(a.len(), points(&a).len() as i64);
+        prop_assert_eq!(points(&a.shift(7)), points(&a).iter().map(|x| x + 7).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn range_map_split(map in range_map(), r in interval()) {
+        let parts = map.split(r);
+        let covered: Vec<i64> = parts.iter().flat_map(|(p, _)| p.start..p.end).collect();
+        prop_assert_eq!(covered, (r.start..r.end).collect::<Vec<_>>());
+        for (p, offset) in parts {
+            for x in p.start..p.end {
+                prop_assert_eq!(map.map(x), x + offset);
+            }
+        }
+    }
+
+    #[test]
+    fn range_map_set(map in range_map(), a in set()) {
+        let mut expected: Vec<i64> = points(&a).iter().map(|&x| map.map(x)).collect();
+        expected.sort();
+        expected.dedup();
+        prop_assert_eq!(points(&map.map_set(&a)), expected);
+    }
+}
+#[test]
+fn test_interval_set() {
+    let set: IntervalSet = [
+        Interval::new(0, 3),
+        Interval::new(5, 8),
+        Interval::new(3, 4),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        set.iter().copied().collect::<Vec<_>>(),
+        [Interval::new(0, 4), Interval::new(5, 8)]
+    );
+    assert_eq!(set.len(), 7);
+    assert_eq!(set.min(), Some(0));
+
+    let mut map = RangeMap::new();
+    map.insert(Interval::new(98, 100), -48).unwrap();
+    map.insert(Interval::new(50, 98), 2).unwrap();
+    assert_eq!(
+        map.insert(Interval::new(90, 92), 1),
+        Err(Interval::new(50, 98))
+    );
+    assert_eq!(map.map(99), 51);
+    assert_eq!(map.map(10), 10);
+    assert_eq!(
+        map.split(Interval::new(40, 99)),
+        [
+            (Interval::new(40, 50), 0),
+            (Interval::new(50, 98), 2),
+            (Interval::new(98, 99), -48)
+        ]
+    );
+}
@@ -0,0 +1,245 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+mod answers;
+pub mod cycle;
+mod fingerprint;
+pub mod graph;
+mod grid;
+pub mod interval;
+pub mod math;
+mod memo;
+pub mod metrics;
+pub mod params;
+mod parse;
+mod pattern;
+mod rng;
+mod solution;
+
+pub use answers::{
+    answers_path, check, check_answers, parse_answers, read_answers, Expected, Outcome,
+};
+pub use fingerprint::{screen_input, Fingerprint, Mismatch};
+pub use grid::{Grid, Pos};
+pub use memo::{Memo, MemoStats};
+pub use params::NoParams;
+pub use parse::{numbered, Line, ParseError};
+pub use pattern::{Captures, Pattern};
+pub use rng::Rng;
+pub use solution::{solver, IntoAnswer, Solution, SolveError, SolveResult, Solved, Solver};
+
+#[derive(Debug)]
+pub enum InputError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Format {
+        path: PathBuf,
+        line: usize,
+        message: String,
+    },
+    Parse(ParseError),
+    Solve(SolveError),
+}
+
+impl From<SolveError> for InputError {
+    fn from(err: SolveError) -> Self {
+        InputError::Solve(err)
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            InputError::Format {
+                path,
+                line,
+                message,
+            } => write!(f, "{}:{}: {}", path.display(), line, message),
+            InputError::Parse(err) => write!(f, "{}", err),
+            InputError::Solve(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            InputError::Format { .. } => None,
+            InputError::Parse(err) => Some(err),
+            InputError::Solve(err) => Some(err),
+        }
+    }
+}
+
+/// Relative names are looked up in the crate directory, so that running from
+/// the workspace root or from the day's own directory finds the same file.
+pub fn resolve<P: AsRef<Path>>(crate_dir: P, name: &str) -> PathBuf {
+    let path = Path::new(name);
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        crate_dir.as_ref().join(path)
+    }
+}
+
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+/// Each day lives in its own aocNN directory next to this crate.
+pub fn day_dir(day: u8) -> PathBuf {
+    workspace_dir().join(format!("aoc{:02}", day))
+}
+
+/// "-" means stdin. Other names are looked up in the day's directory first, so
+/// that "prelim.txt" means the same thing wherever the runner is started from,
+/// and are otherwise taken as an ordinary path.
+pub fn find_input(day: u8, name: &str) -> Option<PathBuf> {
+    if name == "-" {
+        return None;
+    }
+    let path = resolve(day_dir(day), name);
+    if path.exists() {
+        Some(path)
+    } else {
+        Some(PathBuf::from(name))
+    }
+}
+
+/// The lines of a day's input along with a name to report errors against.
+pub fn read_input(day: u8, name: &str) -> Result<(String, Vec<String>), InputError> {
+    match find_input(day, name) {
+        Some(path) => Ok((path.display().to_string(), read_lines(&path)?)),
+        None => Ok(("<stdin>".to_string(), read_stdin()?)),
+    }
+}
+
+pub fn read_stdin() -> Result<Vec<String>, InputError> {
+    let mut text = String::new();
+    io::stdin()
+        .read_to_string(&mut text)
+        .map_err(|source| InputError::Io {
+            path: PathBuf::from("<stdin>"),
+            source,
+        })?;
+    Ok(to_lines(&text))
+}
+
+// Resolve an input file name against the calling crate's directory.
+#[macro_export]
+macro_rules! input_path {
+    ($name:expr) => {
+        $crate::resolve(env!("CARGO_MANIFEST_DIR"), $name)
+    };
+}
+
+pub fn read_string<P: AsRef<Path>>(path: P) -> Result<String, InputError> {
+    let path = path.as_ref();
+    fs::read_to_string(path).map_err(|source| InputError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+pub fn read_lines<P: AsRef<Path>>(path: P) -> Result<Vec<String>, InputError> {
+    Ok(to_lines(&read_string(path)?))
+}
+
+pub fn read_blocks<P: AsRef<Path>>(path: P) -> Result<Vec<Vec<String>>, InputError> {
+    let lines = read_lines(path)?;
+    Ok(split_blocks(&lines)
+        .into_iter()
+        .map(|b| b.to_vec())
+        .collect())
+}
+
+pub fn read_grid<P: AsRef<Path>>(path: P) -> Result<Vec<Vec<char>>, InputError> {
+    Ok(to_grid(&read_lines(path)?))
+}
+
+/// Read and parse a file in one go, as the days' own binaries do.
+pub fn load<S: Solution>(path: impl AsRef<Path>) -> Result<S::Input, InputError> {
+    let path = path.as_ref();
+    let lines = read_lines(path)?;
+    S::parse(&lines).map_err(|err| InputError::Parse(err.with_input(&path.display().to_string())))
+}
+
+/// Trailing blank lines are dropped here, once, so that no parser has to
+/// allow for the extra newline an editor or a paste into stdin leaves behind.
+pub fn to_lines(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = text.lines().map(|l| l.to_string()).collect();
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+/// Split on blank lines, dropping any empty blocks from repeated or trailing
+/// blank lines.
+pub fn split_blocks(lines: &[String]) -> Vec<&[String]> {
+    lines
+        .split(|l| l.is_empty())
+        .filter(|b| !b.is_empty())
+        .collect()
+}
+
+pub fn to_grid(lines: &[String]) -> Vec<Vec<char>> {
+    lines.iter().map(|l| l.chars().collect()).collect()
+}
+
+#[test]
+fn test_resolve() {
+    assert_eq!(resolve("/a/b", "c.txt"), PathBuf::from("/a/b/c.txt"));
+    assert_eq!(resolve("/a/b", "/c.txt"), PathBuf::from("/c.txt"));
+}
+
+#[test]
+fn test_day_dir() {
+    assert!(day_dir(7).ends_with("aoc07"));
+    assert!(day_dir(7).join("prelim.txt").exists());
+}
+
+#[test]
+fn test_find_input() {
+    assert_eq!(find_input(7, "-"), None);
+    assert_eq!(
+        find_input(7, "prelim.txt"),
+        Some(day_dir(7).join("prelim.txt"))
+    );
+    assert_eq!(
+        find_input(7, "no/such/file.txt"),
+        Some(PathBuf::from("no/such/file.txt"))
+    );
+}
+
+#[test]
+fn test_to_lines() {
+    assert_eq!(to_lines("a\n\nb\n\n\n"), ["a", "", "b"]);
+    assert_eq!(to_lines("a\r\nb"), ["a", "b"]);
+    assert!(to_lines("\n\n").is_empty());
+}
+
+#[test]
+fn test_split_blocks() {
+    let lines = to_lines("a\nb\n\nc\n\n\nd\n\n");
+    let blocks = split_blocks(&lines);
+    assert_eq!(blocks.len(), 3);
+    assert_eq!(blocks[0], ["a", "b"]);
+    assert_eq!(blocks[1], ["c"]);
+    assert_eq!(blocks[2], ["d"]);
+}
+
+#[test]
+fn test_missing_file() {
+    let err = read_lines(input_path!("does_not_exist.txt")).unwrap_err();
+    assert!(err.to_string().contains("does_not_exist.txt"));
+}
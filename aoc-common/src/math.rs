@@ -0,0 +1,158 @@
+use std::fmt;
+use std::ops::{Div, Rem};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MathError {
+    Overflow,
+    /// The congruences contradict each other, or an inverse doesn't exist.
+    NoSolution,
+    BadModulus,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MathError::Overflow => write!(f, "arithmetic overflow"),
+            MathError::NoSolution => write!(f, "no solution"),
+            MathError::BadModulus => write!(f, "modulus must be positive"),
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
+
+/// The unsigned types that gcd and lcm work over.
+pub trait Unsigned: Copy + Eq + Div<Output = Self> + Rem<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! unsigned {
+    ($($t:ty),*) => {$(
+        impl Unsigned for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+        }
+    )*};
+}
+
+unsigned!(u32, u64, u128, usize);
+
+pub fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub fn lcm<T: Unsigned>(a: T, b: T) -> Result<T, MathError> {
+    if a == T::ZERO || b == T::ZERO {
+        return Ok(T::ZERO);
+    }
+    // Divide first so that only a result that really doesn't fit overflows.
+    (a / gcd(a, b)).checked_mul(b).ok_or(MathError::Overflow)
+}
+
+pub fn lcm_all<T: Unsigned>(nums: impl IntoIterator<Item = T>) -> Result<T, MathError> {
+    nums.into_iter().try_fold(T::ONE, lcm)
+}
+
+/// Returns (g, x, y) with a*x + b*y = g = gcd(a, b).
+pub fn ext_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The x in 0..m with a*x = 1 (mod m).
+pub fn mod_inv(a: i128, m: i128) -> Result<i128, MathError> {
+    if m <= 0 {
+        return Err(MathError::BadModulus);
+    }
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    if g != 1 {
+        return Err(MathError::NoSolution);
+    }
+    Ok(x.rem_euclid(m))
+}
+
+/// Solve x = r (mod m) for every (r, m) pair at once, returning the smallest
+/// non-negative x and the modulus it repeats with. The moduli don't need to be
+/// coprime, as long as the congruences agree wherever they overlap.
+pub fn crt(congruences: &[(i128, i128)]) -> Result<(i128, i128), MathError> {
+    let mut acc: (i128, i128) = (0, 1);
+    for &(r2, m2) in congruences {
+        if m2 <= 0 {
+            return Err(MathError::BadModulus);
+        }
+        let (r1, m1) = acc;
+        let r2 = r2.rem_euclid(m2);
+        let (g, p, _) = ext_gcd(m1, m2);
+        let diff = r2 - r1;
+        if diff % g != 0 {
+            return Err(MathError::NoSolution);
+        }
+        let m = (m1 / g).checked_mul(m2).ok_or(MathError::Overflow)?;
+        // p is the inverse of m1/g modulo m2/g, so this k lines r1 + k*m1 up
+        // with r2.
+        let step = m2 / g;
+        let k = ((diff / g) % step)
+            .checked_mul(p % step)
+            .ok_or(MathError::Overflow)?
+            .rem_euclid(step);
+        let r = m1
+            .checked_mul(k)
+            .and_then(|x| x.checked_add(r1))
+            .ok_or(MathError::Overflow)?;
+        acc = (r.rem_euclid(m), m);
+    }
+    Ok(acc)
+}
+
+#[test]
+fn test_gcd_lcm() {
+    assert_eq!(gcd(12u64, 18), 6);
+    assert_eq!(gcd(0u64, 5), 5);
+    assert_eq!(lcm(4u64, 6), Ok(12));
+    assert_eq!(lcm_all([2u64, 3, 4, 5]), Ok(60));
+    assert_eq!(lcm(u64::MAX, 2), Err(MathError::Overflow));
+    assert_eq!(lcm(u64::MAX as u128, 2), Ok(2 * u64::MAX as u128));
+}
+
+#[test]
+fn test_mod_inv() {
+    assert_eq!(ext_gcd(240, 46), (2, -9, 47));
+    assert_eq!(mod_inv(3, 11), Ok(4));
+    assert_eq!(mod_inv(-3, 11), Ok(7));
+    assert_eq!(mod_inv(4, 8), Err(MathError::NoSolution));
+    assert_eq!(mod_inv(4, 0), Err(MathError::BadModulus));
+}
+
+#[test]
+fn test_crt() {
+    assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+    // Not coprime, but consistent.
+    assert_eq!(crt(&[(2, 4), (4, 6)]), Ok((10, 12)));
+    assert_eq!(crt(&[(1, 4), (2, 6)]), Err(MathError::NoSolution));
+    assert_eq!(crt(&[]), Ok((0, 1)));
+    let big = i128::MAX / 2;
+    assert_eq!(
+        crt(&[(0, big), (1, big - 1)]).unwrap_err(),
+        MathError::Overflow
+    );
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::mem::size_of;
+use tracing::debug;
+
+/// A cache for recursive solvers. Every value worked out through `get` is kept,
+/// so the solver only has to say how to compute a state from its neighbours.
+/// With `--trace memo` each cache reports the entries it stored in all, its hit
+/// rate and peak memory when it's dropped.
+pub struct Memo<K, V> {
+    name: &'static str,
+    cache: HashMap<K, V>,
+    stats: MemoStats,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MemoStats {
+    /// Every entry stored, including those since forgotten by `clear`.
+    pub entries: usize,
+    pub hits: u64,
+    pub misses: u64,
+    /// Roughly, from the table's capacity rather than the allocator.
+    pub peak_bytes: usize,
+}
+
+impl MemoStats {
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new(name: &'static str) -> Self {
+        Memo {
+            name,
+            cache: HashMap::new(),
+            stats: MemoStats::default(),
+        }
+    }
+
+    /// The cached value for `key`, or else `compute`'s, which is passed the
+    /// memo back for its own recursive calls.
+    pub fn get(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let value = compute(self);
+        if self.cache.insert(key, value.clone()).is_none() {
+            self.stats.entries += 1;
+        }
+        // A control byte per slot on top of the entries themselves.
+        let bytes = self.cache.capacity() * (size_of::<(K, V)>() + 1);
+        self.stats.peak_bytes = self.stats.peak_bytes.max(bytes);
+        value
+    }
+
+    /// Forget the values but keep the statistics and the allocation, for when
+    /// the keys only make sense within one call, e.g. one line of input.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        let stats = &self.stats;
+        debug!(
+            target: "memo",
+            "{}: {} entries, {} hits, {} misses ({:.1}% hit rate), peak {} KiB",
+            self.name,
+            stats.entries,
+            stats.hits,
+            stats.misses,
+            100.0 * stats.hit_rate(),
+            stats.peak_bytes.div_ceil(1024)
+        );
+    }
+}
+
+#[test]
+fn test_memo() {
+    fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        memo.get(n, |memo| match n {
+            0 | 1 => n,
+            _ => fib(memo, n - 1) + fib(memo, n - 2),
+        })
+    }
+    let mut memo = Memo::new("fib");
+    assert_eq!(fib(&mut memo, 90), 2880067194370816120);
+    let stats = memo.stats();
+    assert_eq!((stats.entries, stats.misses, stats.hits), (91, 91, 88));
+    assert!(stats.peak_bytes >= 91 * 16);
+
+    memo.clear();
+    assert!(memo.is_empty());
+    assert_eq!(fib(&mut memo, 2), 1);
+    assert_eq!(memo.len(), 3);
+    assert_eq!((memo.stats().entries, memo.stats().misses), (94, 94));
+}
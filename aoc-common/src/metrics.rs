@@ -0,0 +1,29 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+thread_local! {
+    static METRICS: RefCell<BTreeMap<String, i64>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Record an extra number about the current part, e.g. a loop length, to be
+/// reported next to its answer. Recording the same name again overwrites it.
+pub fn record(name: &str, value: i64) {
+    METRICS.with(|m| m.borrow_mut().insert(name.to_string(), value));
+}
+
+/// Everything recorded on this thread since the last call.
+pub(crate) fn take() -> BTreeMap<String, i64> {
+    METRICS.with(|m| std::mem::take(&mut *m.borrow_mut()))
+}
+
+#[test]
+fn test_record() {
+    take();
+    record("a", 1);
+    record("b", 2);
+    record("a", 3);
+    let metrics = take();
+    assert_eq!(metrics.len(), 2);
+    assert_eq!(metrics["a"], 3);
+    assert!(take().is_empty());
+}
@@ -0,0 +1,155 @@
+use crate::{day_dir, read_string, resolve};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+pub use toml::Table;
+
+/// Each day's parameters are a serde struct with #[serde(default,
+/// deny_unknown_fields)], so that anything left out keeps its puzzle value and
+/// a misspelt name is an error. This one is for the days that have none.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
+
+/// Values that read fine but that the solver can't work with, such as a zero
+/// where it divides or subtracts one, are turned down here with a message.
+pub trait Validate {
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+impl Validate for NoParams {}
+
+thread_local! {
+    static VALUES: RefCell<Table> = RefCell::new(Table::new());
+}
+
+// Put the old values back however `with` is left, including by a panic.
+struct Restore(Option<Table>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        if let Some(old) = self.0.take() {
+            VALUES.with(|v| *v.borrow_mut() = old);
+        }
+    }
+}
+
+/// Run `f` with these values on top of the defaults. They're per thread, so
+/// days solved side by side each see their own.
+pub fn with<T>(values: &Table, f: impl FnOnce() -> T) -> T {
+    let old = VALUES.with(|v| v.replace(values.clone()));
+    let _restore = Restore(Some(old));
+    f()
+}
+
+pub fn read<P: DeserializeOwned + Validate>(values: &Table) -> Result<P, String> {
+    let params: P = toml::Value::Table(values.clone())
+        .try_into()
+        .map_err(|err: toml::de::Error| err.message().to_string())?;
+    params.validate()?;
+    Ok(params)
+}
+
+/// The parameters in effect, which the runner has already checked with `read`.
+pub fn current<P: DeserializeOwned + Validate>() -> P {
+    VALUES
+        .with(|v| read(&v.borrow()))
+        .unwrap_or_else(|err| panic!("bad parameters: {}", err))
+}
+
+/// One `name=value` from the command line or answers.txt. The value is TOML, so
+/// 10, 1e6 and true are typed, and anything else is taken as a string.
+pub fn parse_assignment(text: &str) -> Result<(String, toml::Value), String> {
+    let (name, value) = text
+        .split_once('=')
+        .ok_or_else(|| format!("expected name=value, got {:?}", text))?;
+    let value = match toml::from_str::<Table>(&format!("x = {}", value)) {
+        Ok(mut table) => table.remove("x").unwrap(),
+        Err(_) => toml::Value::String(value.to_string()),
+    };
+    Ok((name.trim().to_string(), value))
+}
+
+/// A TOML file of values, e.g. "max_red = 20", looked up in the day's directory
+/// first like an input.
+pub fn read_file(day: u8, name: &str) -> Result<Table, String> {
+    let mut path = resolve(day_dir(day), name);
+    if !path.exists() {
+        path = PathBuf::from(name);
+    }
+    let text = read_string(&path).map_err(|err| err.to_string())?;
+    toml::from_str(&text).map_err(|err| format!("{}: {}", path.display(), err.message()))
+}
+
+/// The defaults on one line, e.g. "max_red = 12, max_green = 13".
+pub fn describe<P: Default + Serialize>() -> String {
+    let text = toml::to_string(&P::default()).unwrap_or_default();
+    text.lines().collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Limits {
+    max: u64,
+    scale: f64,
+}
+
+#[cfg(test)]
+impl Validate for Limits {
+    fn validate(&self) -> Result<(), String> {
+        if self.scale <= 0.0 {
+            return Err("scale must be positive".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max: 12,
+            scale: 1.0,
+        }
+    }
+}
+
+#[test]
+fn test_params() {
+    assert_eq!(current::<Limits>(), Limits::default());
+    assert_eq!(describe::<Limits>(), "max = 12, scale = 1.0");
+
+    let mut values = Table::new();
+    for text in ["max=20", "scale = 2.5"] {
+        let (name, value) = parse_assignment(text).unwrap();
+        values.insert(name, value);
+    }
+    let limits = with(&values, current::<Limits>);
+    assert_eq!(
+        limits,
+        Limits {
+            max: 20,
+            scale: 2.5
+        }
+    );
+    assert_eq!(current::<Limits>(), Limits::default());
+
+    let (name, value) = parse_assignment("mx=abc").unwrap();
+    assert_eq!(value.as_str(), Some("abc"));
+    values.insert(name, value);
+    assert!(read::<Limits>(&values)
+        .unwrap_err()
+        .contains("unknown field `mx`"));
+    assert!(parse_assignment("max").is_err());
+    let values = Table::from_iter([parse_assignment("scale=0").unwrap()]);
+    assert_eq!(
+        read::<Limits>(&values),
+        Err("scale must be positive".to_string())
+    );
+    assert!(read::<NoParams>(&Table::new()).is_ok());
+}
@@ -0,0 +1,146 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Where and why some input couldn't be parsed. The offending line is kept so
+/// that the error can be shown with a caret under the bad token.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub input: String,
+    /// 1-based, like an editor.
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+    pub text: String,
+}
+
+impl ParseError {
+    /// `index` is the 0-based line index. `token` should be a slice of `text`
+    /// so that its column can be worked out, otherwise its first occurrence
+    /// is used.
+    pub fn new(index: usize, text: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = offset_in(text, token)
+            .or_else(|| text.find(token))
+            .unwrap_or(0);
+        ParseError {
+            input: String::new(),
+            line: index + 1,
+            column: text[..offset].chars().count() + 1,
+            token: token.to_string(),
+            message: message.into(),
+            text: text.to_string(),
+        }
+    }
+
+    /// For input that stops before something required, e.g. an empty file.
+    pub fn missing(index: usize, message: impl Into<String>) -> Self {
+        ParseError::new(index, "", "", message)
+    }
+
+    pub fn with_input(mut self, input: &str) -> Self {
+        self.input = input.to_string();
+        self
+    }
+}
+
+// Byte offset of `token` within `text`, if it really is a slice of it.
+fn offset_in(text: &str, token: &str) -> Option<usize> {
+    let start = text.as_ptr() as usize;
+    let tok = token.as_ptr() as usize;
+    if tok >= start && tok + token.len() <= start + text.len() {
+        Some(tok - start)
+    } else {
+        None
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let input = if self.input.is_empty() {
+            "<input>"
+        } else {
+            &self.input
+        };
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "error: {}", self.message)?;
+        writeln!(f, "{}--> {}:{}:{}", gutter, input, self.line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.token.chars().count().max(1))
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of input together with its index, so that errors can point at it.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.index, self.text, token, message)
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| {
+            let name = std::any::type_name::<T>();
+            self.error(token, format!("expected {}, found {:?}", name, token))
+        })
+    }
+
+    /// Split a piece of this line around the first `sep`.
+    pub fn split_once(&self, token: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+        token
+            .split_once(sep)
+            .ok_or_else(|| self.error(token, format!("expected {:?}", sep)))
+    }
+}
+
+pub fn numbered(lines: &[String]) -> impl Iterator<Item = Line<'_>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, text)| Line { index, text })
+}
+
+#[test]
+fn test_parse_error() {
+    let lines = vec!["32T3K 765".to_string(), "T55X5 684".to_string()];
+    let line = numbered(&lines).nth(1).unwrap();
+    let err = line.error(&line.text[3..4], "unknown card 'X'");
+    assert_eq!((err.line, err.column), (2, 4));
+
+    let err = err.with_input("aoc07/input.txt");
+    let expected = "\
+error: unknown card 'X'
+ --> aoc07/input.txt:2:4
+  |
+2 | T55X5 684
+  |    ^";
+    assert_eq!(err.to_string(), expected);
+}
+
+#[test]
+fn test_line_parse() {
+    let lines = vec!["Time:  7  15  3x0".to_string()];
+    let line = numbered(&lines).next().unwrap();
+    let (_, nums) = line.split_once(line.text, ":").unwrap();
+    let toks: Vec<&str> = nums.split_whitespace().collect();
+    assert_eq!(line.parse::<u64>(toks[1]), Ok(15));
+
+    let err = line.parse::<u64>(toks[2]).unwrap_err();
+    assert_eq!(err.column, 15);
+    assert_eq!(err.token, "3x0");
+    assert!(err.to_string().ends_with("  ^^^"));
+
+    assert!(line.split_once(nums, "|").is_err());
+}
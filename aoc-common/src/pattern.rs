@@ -0,0 +1,224 @@
+use crate::parse::{Line, ParseError};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The shape of a line of puzzle input, written the way the puzzle shows it
+/// with named holes, e.g. "Card {id}: {wins} | {ours}". Whitespace around the
+/// literal text is optional and may be any width, and each hole takes the
+/// trimmed text up to the next literal. A grammar is declared once as a const
+/// and used both to parse lines and to render them in the generators.
+#[derive(Clone, Copy, Debug)]
+pub struct Pattern(&'static str);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Piece {
+    Literal(&'static str),
+    Hole(&'static str),
+}
+
+impl Pattern {
+    pub const fn new(template: &'static str) -> Self {
+        Pattern(template)
+    }
+
+    fn pieces(&self) -> Vec<Piece> {
+        let mut pieces = Vec::new();
+        let mut rest = self.0;
+        while let Some(open) = rest.find('{') {
+            let close = open + rest[open..].find('}').expect("unclosed hole in pattern");
+            if open > 0 {
+                pieces.push(Piece::Literal(&rest[..open]));
+            } else {
+                assert!(
+                    !matches!(pieces.last(), Some(Piece::Hole(_))),
+                    "holes in {:?} need text between them",
+                    self.0
+                );
+            }
+            pieces.push(Piece::Hole(&rest[open + 1..close]));
+            rest = &rest[close + 1..];
+        }
+        if !rest.is_empty() {
+            pieces.push(Piece::Literal(rest));
+        }
+        pieces
+    }
+
+    pub fn parse_line<'a>(&self, line: Line<'a>) -> Result<Captures<'a>, ParseError> {
+        self.parse(line, line.text)
+    }
+
+    /// Match a piece of `line`, so that nested grammars still report columns in
+    /// the whole line.
+    pub fn parse<'a>(&self, line: Line<'a>, text: &'a str) -> Result<Captures<'a>, ParseError> {
+        let pieces = self.pieces();
+        let mut captures = Vec::new();
+        let mut rest = text;
+        for (i, piece) in pieces.iter().enumerate() {
+            match *piece {
+                Piece::Literal(literal) => {
+                    for word in literal.split_whitespace() {
+                        let start = rest.trim_start();
+                        rest = start.strip_prefix(word).ok_or_else(|| {
+                            line.error(next_word(start), format!("expected {:?}", word))
+                        })?;
+                    }
+                }
+                Piece::Hole(name) => {
+                    rest = rest.trim_start();
+                    let end = match pieces.get(i + 1) {
+                        Some(Piece::Literal(literal)) => match literal.split_whitespace().next() {
+                            Some(word) => rest.find(word).ok_or_else(|| {
+                                line.error(next_word(rest), format!("expected {:?}", word))
+                            })?,
+                            None => rest.find(char::is_whitespace).unwrap_or(rest.len()),
+                        },
+                        _ => rest.len(),
+                    };
+                    captures.push((name, rest[..end].trim_end()));
+                    rest = &rest[end..];
+                }
+            }
+        }
+        let extra = rest.trim();
+        if !extra.is_empty() {
+            return Err(line.error(extra, "unexpected text"));
+        }
+        Ok(Captures { line, captures })
+    }
+
+    /// Fill in the holes in order. Padding is up to the caller, e.g. passing
+    /// format!("{:>3}", n) rather than n.
+    pub fn render(&self, values: &[&dyn Display]) -> String {
+        let mut values = values.iter();
+        let mut text = String::new();
+        for piece in self.pieces() {
+            match piece {
+                Piece::Literal(literal) => text += literal,
+                Piece::Hole(name) => {
+                    let value = values
+                        .next()
+                        .unwrap_or_else(|| panic!("no value for {{{}}}", name));
+                    text += &value.to_string();
+                }
+            }
+        }
+        assert!(values.next().is_none(), "too many values for {:?}", self.0);
+        text
+    }
+}
+
+// The word at the start of `text`, or the empty end of it, for an error to
+// point at.
+fn next_word(text: &str) -> &str {
+    let end = text.find(char::is_whitespace).unwrap_or(text.len());
+    &text[..end]
+}
+
+/// What each hole of a pattern matched, still tied to its line for errors.
+#[derive(Clone, Debug)]
+pub struct Captures<'a> {
+    line: Line<'a>,
+    captures: Vec<(&'static str, &'a str)>,
+}
+
+impl<'a> Captures<'a> {
+    pub fn line(&self) -> Line<'a> {
+        self.line
+    }
+
+    pub fn str(&self, name: &str) -> &'a str {
+        self.captures
+            .iter()
+            .find(|&&(hole, _)| hole == name)
+            .unwrap_or_else(|| panic!("no hole named {{{}}}", name))
+            .1
+    }
+
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<T, ParseError> {
+        self.line.parse(self.str(name))
+    }
+
+    /// A whitespace separated list, e.g. "41 48 83".
+    pub fn list<T: FromStr>(&self, name: &str) -> Result<Vec<T>, ParseError> {
+        self.str(name)
+            .split_whitespace()
+            .map(|tok| self.line.parse(tok))
+            .collect()
+    }
+
+    /// A list with some other separator, e.g. "1,1,3".
+    pub fn split<T: FromStr>(&self, name: &str, sep: &str) -> Result<Vec<T>, ParseError> {
+        self.str(name)
+            .split(sep)
+            .map(|tok| self.line.parse(tok.trim()))
+            .collect()
+    }
+}
+
+#[test]
+fn test_pattern() {
+    const CARD: Pattern = Pattern::new("Card {id}: {wins} | {ours}");
+    let lines = vec!["Card   1: 41 48 83 | 83 86  6".to_string()];
+    let line = crate::numbered(&lines).next().unwrap();
+    let card = CARD.parse_line(line).unwrap();
+    assert_eq!(card.get::<u32>("id"), Ok(1));
+    assert_eq!(card.list::<u32>("wins"), Ok(vec![41, 48, 83]));
+    assert_eq!(card.list::<u32>("ours"), Ok(vec![83, 86, 6]));
+    assert_eq!(
+        CARD.render(&[&1, &"41 48", &"83 86"]),
+        "Card 1: 41 48 | 83 86"
+    );
+
+    const NODE: Pattern = Pattern::new("{node} = ({left}, {right})");
+    let lines = vec!["AAA = (BBB, CCC)".to_string()];
+    let node = NODE
+        .parse_line(crate::numbered(&lines).next().unwrap())
+        .unwrap();
+    assert_eq!((node.str("node"), node.str("right")), ("AAA", "CCC"));
+
+    const CUBES: Pattern = Pattern::new("{count} {color}");
+    let lines = vec!["3 blue,  4 red".to_string()];
+    let line = crate::numbered(&lines).next().unwrap();
+    let (_, red) = line.text.split_once(',').unwrap();
+    let cubes = CUBES.parse(line, red).unwrap();
+    assert_eq!(
+        (cubes.get::<u32>("count"), cubes.str("color")),
+        (Ok(4), "red")
+    );
+}
+
+#[test]
+fn test_pattern_errors() {
+    const HAIL: Pattern = Pattern::new("{px}, {py}, {pz} @ {vx}, {vy}, {vz}");
+    let lines = vec![
+        "19, 13, 30 @ -2,  1, -2".to_string(),
+        "19, 13, 30 -2, 1, -2".to_string(),
+        "19, 13, 3x @ -2, 1, -2".to_string(),
+        "19, 13, 30 @ -2, 1, -2, 4".to_string(),
+    ];
+    let mut lines = crate::numbered(&lines);
+    let hail = HAIL.parse_line(lines.next().unwrap()).unwrap();
+    assert_eq!(hail.get::<i64>("vy"), Ok(1));
+
+    let err = HAIL.parse_line(lines.next().unwrap()).unwrap_err();
+    assert_eq!((err.column, err.token.as_str()), (9, "30"));
+    assert_eq!(err.message, "expected \"@\"");
+
+    let hail = HAIL.parse_line(lines.next().unwrap()).unwrap();
+    let err = hail.get::<f64>("pz").unwrap_err();
+    assert_eq!((err.line, err.column, err.token.as_str()), (3, 9, "3x"));
+
+    // The last hole takes the rest of the line.
+    let hail = HAIL.parse_line(lines.next().unwrap()).unwrap();
+    let err = hail.get::<i64>("vz").unwrap_err();
+    assert_eq!((err.column, err.token.as_str()), (21, "-2, 4"));
+
+    const NODE: Pattern = Pattern::new("{node} = ({left}, {right})");
+    let lines = vec!["AAA = (BBB, CCC) DDD".to_string()];
+    let err = NODE
+        .parse_line(crate::numbered(&lines).next().unwrap())
+        .unwrap_err();
+    assert_eq!((err.column, err.token.as_str()), (18, "DDD"));
+    assert_eq!(err.message, "unexpected text");
+}
@@ -0,0 +1,82 @@
+use std::ops::Range;
+
+/// A small seeded generator (SplitMix64) for making up puzzle inputs. The same
+/// seed always gives the same sequence on every platform, which is all we need;
+/// it isn't meant for anything statistical.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in 0..n, without the bias of a plain modulo.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return x % n;
+            }
+        }
+    }
+
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(range.start < range.end, "empty range");
+        let span = range.end.abs_diff(range.start);
+        range.start.wrapping_add(self.below(span) as i64)
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with probability p.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1u64 << 53) as f64
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[test]
+fn test_rng() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    let xs: Vec<u64> = (0..10).map(|_| a.next_u64()).collect();
+    assert_eq!(xs, (0..10).map(|_| b.next_u64()).collect::<Vec<_>>());
+    assert_ne!(Rng::new(43).next_u64(), xs[0]);
+
+    let mut rng = Rng::new(7);
+    for _ in 0..1000 {
+        assert!((-5..5).contains(&rng.range(-5..5)));
+    }
+    assert_eq!(rng.range(i64::MIN..i64::MIN + 1), i64::MIN);
+    assert!(!rng.chance(0.0));
+    assert!(rng.chance(1.0));
+
+    let mut items: Vec<u32> = (0..20).collect();
+    rng.shuffle(&mut items);
+    items.sort();
+    assert_eq!(items, (0..20).collect::<Vec<_>>());
+}
@@ -0,0 +1,296 @@
+use crate::params::{self, Table};
+use crate::{metrics, to_lines, Fingerprint, ParseError, Rng};
+use serde::de::DeserializeOwned;
+use serde::{Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+pub trait Solution {
+    const DAY: u8;
+    /// How many parts are implemented so far; days are solved in order.
+    const PARTS: u8;
+
+    type Input;
+    /// Fallible, so that a part with no answer can say why rather than panic.
+    type Answer: IntoAnswer + From<SolveError>;
+    /// Puzzle constants that can be changed without recompiling; NoParams if
+    /// there aren't any.
+    type Params: DeserializeOwned + Serialize + Default + params::Validate;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError>;
+
+    fn parse_str(text: &str) -> Result<Self::Input, ParseError> {
+        Self::parse(&to_lines(text))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer;
+
+    /// The defaults, with anything set by the runner on top.
+    fn params() -> Self::Params {
+        params::current()
+    }
+
+    /// Days with one part so far have nothing to say to part 2.
+    fn part2(_input: &Self::Input) -> Self::Answer {
+        SolveError::no_answer("it isn't solved yet").into()
+    }
+
+    /// What valid input looks like, checked by the runner before parsing.
+    /// Empty for days that haven't declared one.
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new()
+    }
+
+    /// Make up a valid input, where a scale of 1 is about the size of a real
+    /// one. None for days that don't have a generator.
+    fn generate(_rng: &mut Rng, _scale: usize) -> Option<String> {
+        None
+    }
+}
+
+/// Why a day couldn't be solved: its input didn't parse, or it did but a part
+/// has no answer for it, e.g. day 8 part 1 when there's no AAA node.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SolveError {
+    Parse(ParseError),
+    NoAnswer {
+        input: String,
+        // 0 until solve_timed knows which part it was.
+        part: u8,
+        reason: String,
+    },
+}
+
+impl SolveError {
+    pub fn no_answer(reason: impl Into<String>) -> Self {
+        SolveError::NoAnswer {
+            input: String::new(),
+            part: 0,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn with_input(self, input: &str) -> Self {
+        match self {
+            SolveError::Parse(err) => SolveError::Parse(err.with_input(input)),
+            SolveError::NoAnswer { part, reason, .. } => SolveError::NoAnswer {
+                input: input.to_string(),
+                part,
+                reason,
+            },
+        }
+    }
+
+    fn in_part(self, part: u8) -> Self {
+        match self {
+            SolveError::NoAnswer { input, reason, .. } => SolveError::NoAnswer {
+                input,
+                part,
+                reason,
+            },
+            err => err,
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> Self {
+        SolveError::Parse(err)
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Parse(err) => write!(f, "{}", err),
+            SolveError::NoAnswer {
+                input,
+                part,
+                reason,
+            } => {
+                write!(f, "error: no answer")?;
+                if *part > 0 {
+                    write!(f, " to part {}", part)?;
+                }
+                write!(f, ": {}", reason)?;
+                if !input.is_empty() {
+                    write!(f, "\n --> {}", input)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// What a part returns, the way std's Termination is for main: a Result
+/// holding one of these, which is an error for input with no answer.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<String, SolveError>;
+}
+
+macro_rules! into_answer {
+    ($($t:ty),*) => {$(
+        impl IntoAnswer for $t {
+            fn into_answer(self) -> Result<String, SolveError> {
+                Ok(self.to_string())
+            }
+        }
+    )*};
+}
+
+into_answer!(i32, i64, isize, u32, u64, usize, String);
+
+impl<T: IntoAnswer> IntoAnswer for Result<T, SolveError> {
+    fn into_answer(self) -> Result<String, SolveError> {
+        self?.into_answer()
+    }
+}
+
+impl<T> From<SolveError> for Result<T, SolveError> {
+    fn from(err: SolveError) -> Self {
+        Err(err)
+    }
+}
+
+/// The answer to one part, with how long it took and any metrics the solver
+/// recorded along the way.
+#[derive(Clone, Debug, Serialize)]
+pub struct SolveResult {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+    #[serde(rename = "elapsed_us", serialize_with = "as_micros")]
+    pub elapsed: Duration,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub metrics: BTreeMap<String, i64>,
+}
+
+fn as_micros<S: Serializer>(elapsed: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_f64(elapsed.as_nanos() as f64 / 1000.0)
+}
+
+/// Parsing is shared by all the parts, so its time is kept separately.
+#[derive(Clone, Debug)]
+pub struct Solved {
+    pub parse: Duration,
+    pub results: Vec<SolveResult>,
+}
+
+/// Object safe view of a Solution, so that every day can sit in one table.
+pub trait Solver: Sync {
+    fn day(&self) -> u8;
+    fn parts(&self) -> u8;
+    /// Parse once and run each of the requested parts in order. The input
+    /// name is only used for labelling the results and errors.
+    fn solve_timed(
+        &self,
+        input: &str,
+        lines: &[String],
+        parts: &[u8],
+    ) -> Result<Solved, SolveError>;
+
+    fn solve(&self, lines: &[String], parts: &[u8]) -> Result<Vec<String>, SolveError> {
+        let solved = self.solve_timed("", lines, parts)?;
+        Ok(solved.results.into_iter().map(|r| r.answer).collect())
+    }
+
+    fn solve_str(&self, text: &str, parts: &[u8]) -> Result<Vec<String>, SolveError> {
+        self.solve(&to_lines(text), parts)
+    }
+
+    fn generate(&self, _seed: u64, _scale: usize) -> Option<String> {
+        None
+    }
+
+    /// Whether these would make valid parameters for the day.
+    fn check_params(&self, _values: &Table) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn describe_params(&self) -> String {
+        String::new()
+    }
+
+    fn fingerprint(&self) -> Fingerprint {
+        Fingerprint::new()
+    }
+}
+
+// Format outside the timed region; only the solving counts.
+fn timed<T: IntoAnswer>(f: impl FnOnce() -> T) -> (Result<String, SolveError>, Duration) {
+    let start = Instant::now();
+    let answer = f();
+    let elapsed = start.elapsed();
+    (answer.into_answer(), elapsed)
+}
+
+struct SolverFor<S>(PhantomData<fn() -> S>);
+
+impl<S: Solution> Solver for SolverFor<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parts(&self) -> u8 {
+        S::PARTS
+    }
+
+    fn generate(&self, seed: u64, scale: usize) -> Option<String> {
+        S::generate(&mut Rng::new(seed), scale)
+    }
+
+    fn check_params(&self, values: &Table) -> Result<(), String> {
+        params::read::<S::Params>(values).map(|_| ())
+    }
+
+    fn describe_params(&self) -> String {
+        params::describe::<S::Params>()
+    }
+
+    fn fingerprint(&self) -> Fingerprint {
+        S::fingerprint()
+    }
+
+    fn solve_timed(
+        &self,
+        input: &str,
+        lines: &[String],
+        parts: &[u8],
+    ) -> Result<Solved, SolveError> {
+        let start = Instant::now();
+        let parsed = S::parse(lines).map_err(|err| err.with_input(input))?;
+        let parse = start.elapsed();
+
+        let mut results = Vec::new();
+        for &part in parts {
+            metrics::take();
+            let (answer, elapsed) = match part {
+                1 => timed(|| S::part1(&parsed)),
+                2 => timed(|| S::part2(&parsed)),
+                _ => (
+                    Err(SolveError::no_answer("there's no such part")),
+                    Duration::ZERO,
+                ),
+            };
+            let answer = answer.map_err(|err| err.in_part(part).with_input(input))?;
+            results.push(SolveResult {
+                day: S::DAY,
+                part,
+                input: input.to_string(),
+                answer,
+                elapsed,
+                metrics: metrics::take(),
+            });
+        }
+        Ok(Solved { parse, results })
+    }
+}
+
+pub fn solver<S: Solution + 'static>() -> Box<dyn Solver> {
+    Box::new(SolverFor::<S>(PhantomData))
+}
@@ -0,0 +1,39 @@
+// The one list of days, shared by the runner and the fuzz targets so that a
+// day added here is picked up by both.
+use aoc_common::{solver, Solver};
+
+pub fn all_days() -> Vec<Box<dyn Solver>> {
+    vec![
+        solver::<aoc01::Day01>(),
+        solver::<aoc02::Day02>(),
+        solver::<aoc03::Day03>(),
+        solver::<aoc04::Day04>(),
+        solver::<aoc05::Day05>(),
+        solver::<aoc06::Day06>(),
+        solver::<aoc07::Day07>(),
+        solver::<aoc08::Day08>(),
+        solver::<aoc09::Day09>(),
+        solver::<aoc10::Day10>(),
+        solver::<aoc11::Day11>(),
+        solver::<aoc12::Day12>(),
+        solver::<aoc13::Day13>(),
+        solver::<aoc14::Day14>(),
+        solver::<aoc15::Day15>(),
+        solver::<aoc16::Day16>(),
+        solver::<aoc17::Day17>(),
+        solver::<aoc18::Day18>(),
+        solver::<aoc19::Day19>(),
+        solver::<aoc20::Day20>(),
+        solver::<aoc21::Day21>(),
+        solver::<aoc22::Day22>(),
+        solver::<aoc23::Day23>(),
+        solver::<aoc24::Day24>(),
+        solver::<aoc25::Day25>(),
+    ]
+}
+
+#[test]
+fn test_all_days() {
+    let days: Vec<u8> = all_days().iter().map(|d| d.day()).collect();
+    assert_eq!(days, (1..=25).collect::<Vec<u8>>());
+}
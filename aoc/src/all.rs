@@ -0,0 +1,192 @@
+use aoc_common::{read_input, screen_input, SolveResult, Solver};
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// What happened to one day. A day that fails or panics gets a single row in
+// the summary instead of one per part.
+enum DayOutcome {
+    Solved(Duration, Vec<SolveResult>),
+    Failed(String),
+    Panicked(String),
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+fn solve_day(days: &[Box<dyn Solver>], day: &dyn Solver, parts: &[u8], input: &str) -> DayOutcome {
+    let (name, lines) = match read_input(day.day(), input) {
+        Ok(read) => read,
+        Err(err) => return DayOutcome::Failed(err.to_string()),
+    };
+    if let Err(err) = screen_input(days, day, &lines) {
+        return DayOutcome::Failed(format!("{}: {}", name, err));
+    }
+    match panic::catch_unwind(AssertUnwindSafe(|| day.solve_timed(&name, &lines, parts))) {
+        Ok(Ok(solved)) => DayOutcome::Solved(solved.parse, solved.results),
+        Ok(Err(err)) => DayOutcome::Failed(err.to_string()),
+        Err(payload) => DayOutcome::Panicked(panic_message(payload.as_ref())),
+    }
+}
+
+// Hand the days out to a fixed number of workers, one at a time, so a slow
+// day only holds up its own worker.
+fn solve_all(
+    days: &[Box<dyn Solver>],
+    work: &[(&dyn Solver, Vec<u8>)],
+    input: &str,
+    jobs: usize,
+) -> Vec<DayOutcome> {
+    let next = AtomicUsize::new(0);
+    let outcomes = Mutex::new(Vec::new());
+    thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, work.len().max(1)) {
+            s.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some((day, parts)) = work.get(i) else {
+                    break;
+                };
+                let outcome = solve_day(days, *day, parts, input);
+                outcomes.lock().unwrap().push((i, outcome));
+            });
+        }
+    });
+    let mut outcomes = outcomes.into_inner().unwrap();
+    outcomes.sort_by_key(|&(i, _)| i);
+    outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+}
+
+fn millis(elapsed: Duration) -> String {
+    format!("{:.3}", elapsed.as_secs_f64() * 1000.0)
+}
+
+fn row(day: &str, part: &str, answer: &str, parse: &str, solve: &str, result: &str) {
+    println!(
+        "{:<3} {:<4} {:>16} {:>12} {:>12}  {}",
+        day, part, answer, parse, solve, result
+    );
+}
+
+pub fn run_all(
+    days: &[Box<dyn Solver>],
+    part: Option<u8>,
+    input: &str,
+    jobs: usize,
+    json: bool,
+) -> bool {
+    let work: Vec<(&dyn Solver, Vec<u8>)> = days
+        .iter()
+        .filter(|d| d.parts() > 0)
+        .filter_map(|d| {
+            let parts: Vec<u8> = match part {
+                Some(part) if part > d.parts() => return None,
+                Some(part) => vec![part],
+                None => (1..=d.parts()).collect(),
+            };
+            Some((d.as_ref(), parts))
+        })
+        .collect();
+
+    // The default hook would print each panic as it happens, interleaved with
+    // the other days; they are reported in the summary instead.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let start = Instant::now();
+    let outcomes = solve_all(days, &work, input, jobs);
+    let wall = start.elapsed();
+    panic::set_hook(hook);
+
+    let mut failures = 0;
+    if !json {
+        row("day", "part", "answer", "parse ms", "solve ms", "result");
+    }
+    let mut total = Duration::ZERO;
+    for ((day, _), outcome) in work.iter().zip(&outcomes) {
+        match outcome {
+            DayOutcome::Solved(parse, results) => {
+                total += *parse;
+                for result in results {
+                    total += result.elapsed;
+                    if json {
+                        println!("{}", serde_json::to_string(result).unwrap());
+                    } else {
+                        row(
+                            &result.day.to_string(),
+                            &result.part.to_string(),
+                            &result.answer,
+                            &millis(*parse),
+                            &millis(result.elapsed),
+                            "ok",
+                        );
+                    }
+                }
+            }
+            DayOutcome::Failed(err) => {
+                failures += 1;
+                eprintln!("{}", err);
+                if !json {
+                    row(&day.day().to_string(), "-", "", "", "", "ERROR");
+                }
+            }
+            DayOutcome::Panicked(message) => {
+                failures += 1;
+                eprintln!("Day {:02} panicked: {}", day.day(), message);
+                if !json {
+                    row(&day.day().to_string(), "-", "", "", "", "PANIC");
+                }
+            }
+        }
+    }
+    if !json {
+        println!(
+            "{} days, {} failed, {} ms solving, {} ms wall clock",
+            work.len(),
+            failures,
+            millis(total),
+            millis(wall)
+        );
+    }
+    failures == 0
+}
+
+#[cfg(test)]
+struct Panics;
+
+#[cfg(test)]
+impl Solver for Panics {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parts(&self) -> u8 {
+        1
+    }
+
+    fn solve_timed(
+        &self,
+        _input: &str,
+        _lines: &[String],
+        _parts: &[u8],
+    ) -> Result<aoc_common::Solved, aoc_common::SolveError> {
+        panic!("day {} exploded", self.day())
+    }
+}
+
+#[test]
+fn test_panic_isolated() {
+    let days = aoc_days::all_days();
+    let work: Vec<(&dyn Solver, Vec<u8>)> = vec![(&Panics, vec![1]), (days[9].as_ref(), vec![1])];
+    let outcomes = solve_all(&days, &work, "prelim.txt", 2);
+    assert!(matches!(&outcomes[0], DayOutcome::Panicked(m) if m == "day 1 exploded"));
+    assert!(matches!(&outcomes[1], DayOutcome::Solved(_, r) if r[0].answer == "8"));
+}
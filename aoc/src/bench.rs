@@ -0,0 +1,265 @@
+use aoc_common::{read_answers, read_input, read_string, InputError, Solver};
+use aoc_days::all_days;
+use clap::Args;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Day to benchmark (1-25); every implemented day if omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Part to benchmark (1 or 2); both parts are timed if omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file, looked up in the day's directory first; - reads stdin
+    #[arg(long, default_value = "input.txt")]
+    input: String,
+
+    /// Number of timed runs, after one untimed warm up run
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Compare the medians against a baseline file
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Save the medians to a baseline file, replacing entries already there
+    #[arg(long)]
+    save: Option<PathBuf>,
+
+    /// Flag medians more than this many percent slower than the baseline
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Also time parts whose answers are marked slow
+    #[arg(long)]
+    slow: bool,
+}
+
+// Median and sample variance, in microseconds.
+#[derive(Debug, PartialEq)]
+struct Stats {
+    median: f64,
+    variance: f64,
+}
+
+fn stats(samples: &[Duration]) -> Stats {
+    let mut us: Vec<f64> = samples.iter().map(|d| d.as_secs_f64() * 1e6).collect();
+    us.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let n = us.len();
+    let median = if n.is_multiple_of(2) {
+        (us[n / 2 - 1] + us[n / 2]) / 2.0
+    } else {
+        us[n / 2]
+    };
+    let mean = us.iter().sum::<f64>() / n as f64;
+    let variance = if n > 1 {
+        us.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / (n - 1) as f64
+    } else {
+        0.0
+    };
+    Stats { median, variance }
+}
+
+// Baseline medians keyed by (day, phase, input), one "day phase input
+// median_us" per line.
+type Baseline = BTreeMap<(u8, String, String), f64>;
+
+fn parse_baseline(text: &str) -> Result<Baseline, (usize, String)> {
+    let mut baseline = Baseline::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let toks: Vec<&str> = line.split_whitespace().collect();
+        let entry = match toks[..] {
+            [day, phase, input, median] => day
+                .parse::<u8>()
+                .ok()
+                .zip(median.parse::<f64>().ok())
+                .map(|(day, median)| ((day, phase.to_string(), input.to_string()), median)),
+            _ => None,
+        };
+        match entry {
+            Some((key, median)) => {
+                baseline.insert(key, median);
+            }
+            None => return Err((i + 1, format!("bad baseline entry {:?}", line))),
+        }
+    }
+    Ok(baseline)
+}
+
+fn read_baseline(path: &Path) -> Result<Baseline, InputError> {
+    let text = read_string(path)?;
+    parse_baseline(&text).map_err(|(line, message)| InputError::Format {
+        path: path.to_path_buf(),
+        line,
+        message,
+    })
+}
+
+fn write_baseline(path: &Path, baseline: &Baseline) -> std::io::Result<()> {
+    let mut text = String::from("# day phase input median_us\n");
+    for ((day, phase, input), median) in baseline {
+        text += &format!("{} {} {} {:.3}\n", day, phase, input, median);
+    }
+    fs::write(path, text)
+}
+
+// Parts recorded as slow in answers.txt for this input.
+fn is_slow(day: u8, part: u8, input: &str) -> bool {
+    read_answers()
+        .map(|entries| {
+            entries
+                .iter()
+                .any(|e| e.day == day && e.part == part && e.input == input && e.slow)
+        })
+        .unwrap_or(false)
+}
+
+fn bench_day(day: &dyn Solver, args: &BenchArgs) -> Result<Vec<(String, Stats)>, String> {
+    let mut parts: Vec<u8> = match args.part {
+        Some(part) if part > day.parts() => {
+            return Err(format!("part {} not implemented", part));
+        }
+        Some(part) => vec![part],
+        None => (1..=day.parts()).collect(),
+    };
+    parts.retain(|&p| args.slow || !is_slow(day.day(), p, &args.input));
+
+    let (name, lines) = read_input(day.day(), &args.input).map_err(|e| e.to_string())?;
+
+    // The warm up run also catches bad input before any timing.
+    day.solve_timed(&name, &lines, &parts)
+        .map_err(|e| e.to_string())?;
+    let mut samples = vec![Vec::new(); parts.len() + 1];
+    for _ in 0..args.runs {
+        let solved = day.solve_timed(&args.input, &lines, &parts).unwrap();
+        samples[0].push(solved.parse);
+        for (i, result) in solved.results.into_iter().enumerate() {
+            samples[i + 1].push(result.elapsed);
+        }
+    }
+
+    let phases =
+        std::iter::once("parse".to_string()).chain(parts.iter().map(|p| format!("part{}", p)));
+    Ok(phases.zip(samples.iter().map(|s| stats(s))).collect())
+}
+
+pub fn bench(args: &BenchArgs) -> bool {
+    let baseline = match &args.baseline {
+        Some(path) => match read_baseline(path) {
+            Ok(baseline) => baseline,
+            Err(err) => {
+                eprintln!("{}", err);
+                return false;
+            }
+        },
+        None => Baseline::new(),
+    };
+
+    let days: Vec<_> = all_days()
+        .into_iter()
+        .filter(|d| d.parts() > 0 && args.day.is_none_or(|day| day == d.day()))
+        .collect();
+    if days.is_empty() {
+        eprintln!("Day {:02}: not implemented", args.day.unwrap());
+        return false;
+    }
+
+    let mut ok = true;
+    let mut results = Baseline::new();
+    println!(
+        "{:<3} {:<6} {:>14} {:>18} {:>14}  change",
+        "day", "phase", "median (us)", "variance (us^2)", "baseline (us)"
+    );
+    for day in &days {
+        let rows = match bench_day(day.as_ref(), args) {
+            Ok(rows) => rows,
+            Err(err) => {
+                eprintln!("Day {:02}: {}", day.day(), err);
+                ok = false;
+                continue;
+            }
+        };
+        for (phase, stats) in rows {
+            let key = (day.day(), phase, args.input.clone());
+            let (base, change) = match baseline.get(&key) {
+                Some(&base) => {
+                    let pct = (stats.median - base) / base * 100.0;
+                    let flag = if pct > args.threshold {
+                        ok = false;
+                        "  REGRESSION"
+                    } else {
+                        ""
+                    };
+                    (format!("{:.1}", base), format!("{:+.1}%{}", pct, flag))
+                }
+                None => (String::new(), String::new()),
+            };
+            println!(
+                "{:<3} {:<6} {:>14.1} {:>18.1} {:>14}  {}",
+                key.0, key.1, stats.median, stats.variance, base, change
+            );
+            results.insert(key, stats.median);
+        }
+    }
+
+    if let Some(path) = &args.save {
+        // Keep entries for days and inputs that weren't run this time.
+        let mut saved = if path.exists() {
+            match read_baseline(path) {
+                Ok(saved) => saved,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return false;
+                }
+            }
+        } else {
+            Baseline::new()
+        };
+        saved.extend(results);
+        if let Err(err) = write_baseline(path, &saved) {
+            eprintln!("{}: {}", path.display(), err);
+            return false;
+        }
+    }
+    ok
+}
+
+#[test]
+fn test_stats() {
+    let ms = |v: &[u64]| -> Vec<Duration> { v.iter().map(|&m| Duration::from_millis(m)).collect() };
+    assert_eq!(
+        stats(&ms(&[3, 1, 2])),
+        Stats {
+            median: 2000.0,
+            variance: 1e6
+        }
+    );
+    assert_eq!(stats(&ms(&[4, 1, 2, 3])).median, 2500.0);
+    assert_eq!(stats(&ms(&[5])).variance, 0.0);
+}
+
+#[test]
+fn test_baseline_round_trip() {
+    let mut baseline = Baseline::new();
+    baseline.insert((5, "part2".to_string(), "input.txt".to_string()), 1234.5);
+    baseline.insert((12, "parse".to_string(), "prelim.txt".to_string()), 0.25);
+
+    let path = std::env::temp_dir().join(format!("aoc-baseline-{}.txt", std::process::id()));
+    write_baseline(&path, &baseline).unwrap();
+    let read = read_baseline(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(read, baseline);
+
+    assert_eq!(parse_baseline("1 parse input.txt").unwrap_err().0, 1);
+}
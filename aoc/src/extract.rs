@@ -0,0 +1,275 @@
+use aoc_common::params::Table;
+use aoc_common::{answers_path, day_dir, parse_answers, read_string, Expected};
+use clap::Args;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct ExtractArgs {
+    /// Day the page is for (1-25)
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The puzzle page as saved from the browser
+    page: PathBuf,
+
+    /// Overwrite example files that already have different contents
+    #[arg(long)]
+    force: bool,
+}
+
+// An example input from the page, with the answer each part gives for it.
+#[derive(Debug, PartialEq)]
+struct Example {
+    text: String,
+    answers: [Option<String>; 2],
+}
+
+enum Event {
+    Block(String),
+    Answer(String),
+}
+
+fn decode(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    // Drop any markup inside the block, e.g. <em> around part of an example.
+    while let Some(i) = rest.find('<') {
+        text += &rest[..i];
+        rest = rest[i..].find('>').map_or("", |j| &rest[i + j + 1..]);
+    }
+    text += rest;
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+// Everything between each `open` and the following `close`, with its offset.
+fn spans<'a>(html: &'a str, open: &str, close: &str) -> Vec<(usize, &'a str)> {
+    let mut found = Vec::new();
+    let mut from = 0;
+    while let Some(i) = html[from..].find(open) {
+        let start = from + i + open.len();
+        let Some(len) = html[start..].find(close) else {
+            break;
+        };
+        found.push((from + i, &html[start..start + len]));
+        from = start + len + close.len();
+    }
+    found
+}
+
+// Each part's description is an <article>. Example inputs are <pre><code>
+// blocks, and the answer for an example is the last emphasised code after it
+// in the same part. Part 2 usually reuses part 1's example without repeating
+// it, so its answers go to the last example seen.
+fn find_examples(html: &str) -> Vec<Example> {
+    let mut articles: Vec<&str> = spans(html, "<article", "</article>")
+        .into_iter()
+        .map(|(_, a)| a)
+        .collect();
+    if articles.is_empty() {
+        articles.push(html);
+    }
+
+    let mut examples: Vec<Example> = Vec::new();
+    for (part, article) in articles.into_iter().take(2).enumerate() {
+        let mut events: Vec<(usize, Event)> = Vec::new();
+        for (at, block) in spans(article, "<pre><code>", "</code></pre>") {
+            events.push((at, Event::Block(decode(block))));
+        }
+        for pattern in [
+            ("<code><em>", "</em></code>"),
+            ("<em><code>", "</code></em>"),
+        ] {
+            for (at, answer) in spans(article, pattern.0, pattern.1) {
+                events.push((at, Event::Answer(decode(answer))));
+            }
+        }
+        events.sort_by_key(|&(at, _)| at);
+
+        let mut current = examples.len().checked_sub(1);
+        for (_, event) in events {
+            match event {
+                Event::Block(text) => match examples.iter().position(|e| e.text == text) {
+                    Some(i) => current = Some(i),
+                    None => {
+                        examples.push(Example {
+                            text,
+                            answers: [None, None],
+                        });
+                        current = Some(examples.len() - 1);
+                    }
+                },
+                Event::Answer(answer)
+                    if !answer.is_empty() && !answer.contains(char::is_whitespace) =>
+                {
+                    if let Some(i) = current {
+                        examples[i].answers[part] = Some(answer);
+                    }
+                }
+                Event::Answer(_) => {}
+            }
+        }
+    }
+    examples
+}
+
+fn file_name(i: usize) -> String {
+    if i == 0 {
+        "prelim.txt".to_string()
+    } else {
+        format!("prelim{}.txt", i + 1)
+    }
+}
+
+fn write_example(path: &PathBuf, text: &str, force: bool) -> Result<&'static str, String> {
+    let action = match fs::read_to_string(path) {
+        Ok(existing) if existing.trim_end() == text.trim_end() => return Ok("unchanged"),
+        // A placeholder from `aoc new`.
+        Ok(existing) if existing.trim().is_empty() => "filled",
+        Ok(_) if force => "replaced",
+        Ok(_) => return Ok("differs, kept (use --force)"),
+        Err(_) => "created",
+    };
+    let mut text = text.to_string();
+    if !text.ends_with('\n') {
+        text.push('\n');
+    }
+    fs::write(path, text).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(action)
+}
+
+// Put each new entry after the last existing one for the same or an earlier
+// day, so the file stays in day order.
+fn add_answers(text: &str, new: &[Expected]) -> String {
+    let mut lines: Vec<String> = text.lines().map(|l| l.to_string()).collect();
+    for entry in new {
+        let at = lines
+            .iter()
+            .rposition(|l| {
+                parse_answers(l).is_ok_and(|e| e.first().is_some_and(|e| e.day <= entry.day))
+            })
+            .map_or(lines.len(), |i| i + 1);
+        lines.insert(at, entry.to_string());
+    }
+    lines.join("\n") + "\n"
+}
+
+fn extract_day(args: &ExtractArgs) -> Result<(), String> {
+    let html = read_string(&args.page).map_err(|e| e.to_string())?;
+    let examples = find_examples(&html);
+    if examples.is_empty() {
+        return Err(format!(
+            "{}: no <pre><code> examples found",
+            args.page.display()
+        ));
+    }
+
+    let dir = day_dir(args.day);
+    let path = answers_path();
+    let answers_text = read_string(&path).map_err(|e| e.to_string())?;
+    let known = parse_answers(&answers_text)
+        .map_err(|(line, message)| format!("{}:{}: {}", path.display(), line, message))?;
+    let mut new = Vec::new();
+    for (i, example) in examples.iter().enumerate() {
+        let name = file_name(i);
+        let action = write_example(&dir.join(&name), &example.text, args.force)?;
+        println!("{:>10} aoc{:02}/{}", action, args.day, name);
+
+        for (part, answer) in (1..=2).zip(&example.answers) {
+            let Some(answer) = answer else {
+                continue;
+            };
+            let entry = Expected {
+                day: args.day,
+                part,
+                input: name.clone(),
+                answer: answer.clone(),
+                slow: false,
+                params: Table::new(),
+            };
+            match known.iter().find(|e| {
+                e.day == entry.day
+                    && e.part == part
+                    && e.input == entry.input
+                    && e.params.is_empty()
+            }) {
+                Some(e) if e.answer == entry.answer => {}
+                Some(e) => eprintln!("answers.txt has {}, the page says {}", e, entry.answer),
+                None => {
+                    println!("{:>10} {}", "answer", entry);
+                    new.push(entry);
+                }
+            }
+        }
+    }
+    if !new.is_empty() {
+        fs::write(&path, add_answers(&answers_text, &new))
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+pub fn extract(args: &ExtractArgs) -> bool {
+    match extract_day(args) {
+        Ok(()) => true,
+        Err(err) => {
+            eprintln!("{}", err);
+            false
+        }
+    }
+}
+
+#[test]
+fn test_find_examples() {
+    let html = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<p>Here the values are <code>12</code> and <code><em>38</em></code>, which sum to <code><em>50</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54331</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>two1nine
+<em>eight</em>wo&lt;three
+</code></pre>
+<p>Adding these together produces <em><code>281</code></em>.</p>
+</article>
+</main>"#;
+    let examples = find_examples(html);
+    assert_eq!(
+        examples,
+        [
+            Example {
+                text: "1abc2\npqr3stu8vwx\n".to_string(),
+                answers: [Some("50".to_string()), None],
+            },
+            Example {
+                text: "two1nine\neightwo<three\n".to_string(),
+                answers: [None, Some("281".to_string())],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_add_answers() {
+    let text = "# header\n1 1 prelim.txt 142\n3 1 prelim.txt 4361\n";
+    let new = [Expected {
+        day: 2,
+        part: 1,
+        input: "prelim.txt".to_string(),
+        answer: "8".to_string(),
+        slow: false,
+        params: Table::new(),
+    }];
+    assert_eq!(
+        add_answers(text, &new),
+        "# header\n1 1 prelim.txt 142\n2 1 prelim.txt 8\n3 1 prelim.txt 4361\n"
+    );
+}
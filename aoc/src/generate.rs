@@ -0,0 +1,57 @@
+use aoc_days::all_days;
+use clap::Args;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct GenArgs {
+    /// Day to make an input for (1-25)
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The same seed always gives the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Size relative to a real input, e.g. 10 or 100 for stress tests
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    scale: u64,
+
+    /// Write the input here instead of to stdout
+    #[arg(long)]
+    out: Option<PathBuf>,
+}
+
+pub fn generate(args: &GenArgs) -> bool {
+    let days = all_days();
+    let day = days[args.day as usize - 1].as_ref();
+    let Some(text) = day.generate(args.seed, args.scale as usize) else {
+        eprintln!("Day {:02}: no input generator", args.day);
+        return false;
+    };
+    match &args.out {
+        Some(path) => match fs::write(path, text) {
+            Ok(()) => true,
+            Err(err) => {
+                eprintln!("{}: {}", path.display(), err);
+                false
+            }
+        },
+        None => {
+            print!("{}", text);
+            true
+        }
+    }
+}
+
+#[test]
+fn test_generated_inputs() {
+    for day in all_days().iter().filter(|d| d.parts() > 0) {
+        let text = day.generate(1, 1).unwrap();
+        assert_eq!(day.generate(1, 1).as_ref(), Some(&text));
+        let parts: Vec<u8> = (1..=day.parts()).collect();
+        if let Err(err) = day.solve_str(&text, &parts) {
+            panic!("day {}: {}", day.day(), err);
+        }
+    }
+}
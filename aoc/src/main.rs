@@ -0,0 +1,346 @@
+use aoc_common::params::{self, Table};
+use aoc_common::{check, read_answers, read_input, screen_input, Outcome, Solver};
+use aoc_days::all_days;
+use clap::{Args, Parser, Subcommand};
+use std::process::ExitCode;
+use tracing_subscriber::EnvFilter;
+
+mod all;
+mod bench;
+mod extract;
+mod generate;
+mod new;
+
+use all::run_all;
+use bench::{bench, BenchArgs};
+use extract::{extract, ExtractArgs};
+use generate::{generate, GenArgs};
+use new::{new, NewArgs};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run the Advent of Code 2023 solvers")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Show diagnostics on stderr, e.g. "day10::loop" or "day12=debug"
+    /// (comma separated; RUST_LOG is used if not given)
+    #[arg(long, global = true)]
+    trace: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day's solver, or every implemented day with --all
+    Run(RunArgs),
+    /// List which days and parts are implemented
+    List,
+    /// Check every answer recorded in answers.txt
+    Verify(VerifyArgs),
+    /// Time parsing and each part over repeated runs
+    Bench(BenchArgs),
+    /// Create a day's crate from the template, or fill in what's missing
+    New(NewArgs),
+    /// Pull the examples and their answers out of a saved puzzle page
+    Extract(ExtractArgs),
+    /// Make up a random input for a day from a seed, at any size
+    Gen(GenArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run (1-25)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Part to run (1 or 2); both parts are run if omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file, looked up in the day's directory first; - reads stdin
+    #[arg(long, default_value = "input.txt")]
+    input: String,
+
+    /// Same as --input
+    #[arg(value_name = "INPUT", conflicts_with = "input")]
+    file: Option<String>,
+
+    /// Run every implemented day concurrently and print a summary table
+    #[arg(long)]
+    all: bool,
+
+    /// How many days --all runs at once (defaults to the number of CPUs)
+    #[arg(long, requires = "all")]
+    jobs: Option<usize>,
+
+    /// Print one JSON object per part instead of plain text
+    #[arg(long)]
+    json: bool,
+
+    /// TOML file of the day's parameters, looked up in the day's directory first
+    #[arg(long, value_name = "FILE", conflicts_with = "all")]
+    params: Option<String>,
+
+    /// Set one of the day's parameters, e.g. --param max_red=20 (repeatable,
+    /// and applied on top of --params)
+    #[arg(long, value_name = "NAME=VALUE", conflicts_with = "all")]
+    param: Vec<String>,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only check this day (1-25)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Also check answers marked slow
+    #[arg(long)]
+    slow: bool,
+}
+
+fn read_params(day: u8, args: &RunArgs) -> Result<Table, String> {
+    let mut values = match &args.params {
+        Some(name) => params::read_file(day, name)?,
+        None => Table::new(),
+    };
+    for assignment in &args.param {
+        let (name, value) = params::parse_assignment(assignment)?;
+        values.insert(name, value);
+    }
+    Ok(values)
+}
+
+fn run_day(
+    days: &[Box<dyn Solver>],
+    day: &dyn Solver,
+    part: Option<u8>,
+    input: &str,
+    values: &Table,
+    json: bool,
+) -> bool {
+    if day.parts() == 0 {
+        eprintln!("Day {:02}: not implemented", day.day());
+        return false;
+    }
+    let parts = match part {
+        Some(part) if part > day.parts() => {
+            eprintln!("Day {:02} part {}: not implemented", day.day(), part);
+            return false;
+        }
+        Some(part) => vec![part],
+        None => (1..=day.parts()).collect(),
+    };
+
+    let (name, lines) = match read_input(day.day(), input) {
+        Ok(read) => read,
+        Err(err) => {
+            eprintln!("Day {:02}: {}", day.day(), err);
+            return false;
+        }
+    };
+
+    if let Err(err) = screen_input(days, day, &lines) {
+        eprintln!("Day {:02}: {}: {}", day.day(), name, err);
+        return false;
+    }
+    if let Err(err) = day.check_params(values) {
+        eprintln!("Day {:02}: bad parameters: {}", day.day(), err);
+        return false;
+    }
+    let solved = params::with(values, || day.solve_timed(input, &lines, &parts));
+    let solved = match solved {
+        Ok(solved) => solved,
+        Err(err) => {
+            eprintln!("{}", err.with_input(&name));
+            return false;
+        }
+    };
+    for result in solved.results {
+        if json {
+            println!("{}", serde_json::to_string(&result).unwrap());
+        } else {
+            println!(
+                "Day {:02} part {}: {}",
+                result.day, result.part, result.answer
+            );
+        }
+    }
+    true
+}
+
+fn run(args: &RunArgs) -> bool {
+    let days = all_days();
+    let input = args.file.as_deref().unwrap_or(&args.input);
+    if args.all {
+        if input == "-" {
+            eprintln!("--all can't read from stdin");
+            return false;
+        }
+        let jobs = args
+            .jobs
+            .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()));
+        run_all(&days, args.part, input, jobs, args.json)
+    } else {
+        let day = args.day.unwrap();
+        let values = match read_params(day, args) {
+            Ok(values) => values,
+            Err(err) => {
+                eprintln!("Day {:02}: {}", day, err);
+                return false;
+            }
+        };
+        let day = days[day as usize - 1].as_ref();
+        run_day(&days, day, args.part, input, &values, args.json)
+    }
+}
+
+fn list() {
+    for day in all_days() {
+        let parts: Vec<_> = (1..=2)
+            .map(|p| {
+                if p <= day.parts() {
+                    format!("part {}", p)
+                } else {
+                    "-".to_string()
+                }
+            })
+            .collect();
+        let params = day.describe_params();
+        if params.is_empty() {
+            println!("Day {:02}: {}", day.day(), parts.join(" "));
+        } else {
+            println!("Day {:02}: {}  {}", day.day(), parts.join(" "), params);
+        }
+    }
+}
+
+fn verify(args: &VerifyArgs) -> bool {
+    let entries = match read_answers() {
+        Ok(entries) => entries,
+        Err(err) => {
+            eprintln!("{}", err);
+            return false;
+        }
+    };
+    let days = all_days();
+
+    let (mut passed, mut failed, mut missing, mut skipped) = (0, 0, 0, 0);
+    println!(
+        "{:<3} {:<4} {:<16} {:>16} {:>16}  result",
+        "day", "part", "input", "expected", "actual"
+    );
+    for e in entries
+        .iter()
+        .filter(|e| args.day.is_none_or(|d| d == e.day))
+    {
+        if e.slow && !args.slow {
+            skipped += 1;
+            continue;
+        }
+        let (actual, result) = match check(days[e.day as usize - 1].as_ref(), e) {
+            Outcome::Pass(actual) => {
+                passed += 1;
+                (actual, "pass".to_string())
+            }
+            Outcome::Fail(actual) => {
+                failed += 1;
+                (actual, "FAIL".to_string())
+            }
+            Outcome::Missing(reason) => {
+                missing += 1;
+                (String::new(), format!("missing ({})", reason))
+            }
+            Outcome::Error(err) => {
+                failed += 1;
+                eprintln!("{}", err);
+                (String::new(), "ERROR".to_string())
+            }
+        };
+        println!(
+            "{:<3} {:<4} {:<16} {:>16} {:>16}  {}",
+            e.day, e.part, e.input, e.answer, actual, result
+        );
+    }
+    println!(
+        "{} passed, {} failed, {} missing, {} slow skipped",
+        passed, failed, missing, skipped
+    );
+    failed == 0 && missing == 0
+}
+
+// Diagnostics go to stderr so they never mix with answers or JSON output.
+fn init_tracing(trace: Option<&str>) {
+    let filter = match trace {
+        Some(directives) => EnvFilter::new(directives),
+        None => EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("off")),
+    };
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .without_time()
+        .init();
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_tracing(cli.trace.as_deref());
+    let ok = match &cli.command {
+        Command::Run(args) => run(args),
+        Command::List => {
+            list();
+            true
+        }
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::New(args) => new(args),
+        Command::Extract(args) => extract(args),
+        Command::Gen(args) => generate(args),
+    };
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[test]
+fn test_json_result() {
+    let (_, lines) = read_input(10, "prelim.txt").unwrap();
+    let results = all_days()[9]
+        .solve_timed("prelim.txt", &lines, &[1, 2])
+        .unwrap()
+        .results;
+    let json: Vec<serde_json::Value> = results
+        .iter()
+        .map(|r| serde_json::to_value(r).unwrap())
+        .collect();
+    assert_eq!(json[0]["day"], 10);
+    assert_eq!(json[0]["input"], "prelim.txt");
+    assert_eq!(json[0]["answer"], "8");
+    assert_eq!(json[0]["metrics"]["loop_len"], 16);
+    assert!(json[1].get("metrics").is_none());
+}
+
+#[test]
+fn test_screen_input() {
+    let days = all_days();
+    for e in read_answers().unwrap() {
+        let (_, lines) = read_input(e.day, &e.input).unwrap();
+        let fingerprint = days[e.day as usize - 1].fingerprint();
+        assert_eq!(fingerprint.check(&lines), Ok(()), "{}", e);
+    }
+    for day in &days {
+        if let Some(text) = day.generate(1, 1) {
+            let lines = aoc_common::to_lines(&text);
+            assert_eq!(day.fingerprint().check(&lines), Ok(()), "day {}", day.day());
+        }
+    }
+
+    let (_, lines) = read_input(7, "prelim.txt").unwrap();
+    let err = screen_input(&days, days[7].as_ref(), &lines).unwrap_err();
+    assert_eq!(err, "this looks like day 7 input");
+    let (_, lines) = read_input(5, "prelim.txt").unwrap();
+    let err = screen_input(&days, days[4].as_ref(), &lines[..10]).unwrap_err();
+    assert_eq!(err, "input truncated at line 10");
+}
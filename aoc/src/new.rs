@@ -0,0 +1,151 @@
+use aoc_common::{day_dir, workspace_dir};
+use clap::Args;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Args)]
+pub struct NewArgs {
+    /// Day to set up (1-25)
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Parse the input as a Grid<char> rather than a list of lines
+    #[arg(long)]
+    grid: bool,
+}
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.in");
+const LIB_RS: &str = include_str!("../templates/lib.rs.in");
+const MAIN_RS: &str = include_str!("../templates/main.rs.in");
+const API_RS: &str = include_str!("../templates/api.rs.in");
+
+// What `cargo new` leaves behind, which is safe to replace.
+const STUB_MAIN: &str = "fn main() {\n    println!(\"Hello, world!\");\n}\n";
+
+// What the skeleton parses the input into, and how.
+const LINES: [(&str, &str); 3] = [
+    (
+        "{{USE}}",
+        "use aoc_common::{NoParams, ParseError, Solution, SolveError};",
+    ),
+    ("{{INPUT}}", "Vec<String>"),
+    ("{{PARSE}}", "Ok(lines.to_vec())"),
+];
+const GRID: [(&str, &str); 3] = [
+    (
+        "{{USE}}",
+        "use aoc_common::{Grid, NoParams, ParseError, Solution, SolveError};",
+    ),
+    ("{{INPUT}}", "Grid<char>"),
+    ("{{PARSE}}", "Grid::parse_chars(lines)"),
+];
+
+fn render(template: &str, day: u8, grid: bool) -> String {
+    let input = if grid { GRID } else { LINES };
+    input
+        .iter()
+        .fold(template.to_string(), |text, (hole, code)| {
+            text.replace(hole, code)
+        })
+        .replace("{{NN}}", &format!("{:02}", day))
+        .replace("{{DAY}}", &day.to_string())
+}
+
+// Only missing files and untouched `cargo new` stubs get written, so running
+// this on a day that's already underway is harmless.
+fn fill(path: &Path, contents: &str) -> io::Result<&'static str> {
+    let action = match fs::read_to_string(path) {
+        Ok(existing) if existing == STUB_MAIN => "replaced stub",
+        Ok(_) => return Ok("kept"),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => "created",
+        Err(err) => return Err(err),
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)?;
+    Ok(action)
+}
+
+// Add a crate to the workspace members list, keeping it sorted. None if it's
+// already there.
+fn add_member(manifest: &str, name: &str) -> Option<String> {
+    let start = manifest.find("members = [")?;
+    let end = start + manifest[start..].find(']')?;
+    let list = &manifest[start + "members = [".len()..end];
+    let mut members: Vec<&str> = list
+        .split(',')
+        .map(|m| m.trim().trim_matches('"'))
+        .filter(|m| !m.is_empty())
+        .collect();
+    if members.contains(&name) {
+        return None;
+    }
+    members.push(name);
+    members.sort();
+    let list: String = members.iter().map(|m| format!("  \"{}\",\n", m)).collect();
+    Some(format!(
+        "{}members = [\n{}{}",
+        &manifest[..start],
+        list,
+        &manifest[end..]
+    ))
+}
+
+fn new_day(day: u8, grid: bool) -> io::Result<()> {
+    let dir = day_dir(day);
+    let files = [
+        ("Cargo.toml", render(CARGO_TOML, day, grid)),
+        ("src/lib.rs", render(LIB_RS, day, grid)),
+        ("src/main.rs", render(MAIN_RS, day, grid)),
+        ("tests/api.rs", render(API_RS, day, grid)),
+        ("prelim.txt", String::new()),
+        ("input.txt", String::new()),
+    ];
+    for (name, contents) in files {
+        let action = fill(&dir.join(name), &contents)?;
+        println!("{:>13} aoc{:02}/{}", action, day, name);
+    }
+
+    let manifest_path = workspace_dir().join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)?;
+    if let Some(updated) = add_member(&manifest, &format!("aoc{:02}", day)) {
+        fs::write(&manifest_path, updated)?;
+        println!("{:>13} aoc{:02} to the workspace members", "added", day);
+    }
+    Ok(())
+}
+
+pub fn new(args: &NewArgs) -> bool {
+    match new_day(args.day, args.grid) {
+        Ok(()) => true,
+        Err(err) => {
+            eprintln!("Day {:02}: {}", args.day, err);
+            false
+        }
+    }
+}
+
+#[test]
+fn test_add_member() {
+    let manifest = "[workspace]\nmembers = [\n  \"aoc\",\n  \"aoc02\",\n]\n";
+    let updated = add_member(manifest, "aoc01").unwrap();
+    assert_eq!(
+        updated,
+        "[workspace]\nmembers = [\n  \"aoc\",\n  \"aoc01\",\n  \"aoc02\",\n]\n"
+    );
+    assert_eq!(add_member(&updated, "aoc02"), None);
+}
+
+#[test]
+fn test_render() {
+    let lib = render(LIB_RS, 7, false);
+    assert!(lib.contains("pub struct Day07;"));
+    assert!(lib.contains("const DAY: u8 = 7;"));
+    assert!(lib.contains("type Input = Vec<String>;"));
+    assert!(!lib.contains("{{"));
+    let lib = render(LIB_RS, 7, true);
+    assert!(lib.contains("type Input = Grid<char>;"));
+    assert!(!lib.contains("{{"));
+    assert!(render(API_RS, 7, false).contains("use aoc07::Day07;"));
+}
@@ -0,0 +1,145 @@
+use aoc_common::{numbered, Fingerprint, NoParams, ParseError, Rng, Solution, SolveError};
+use tracing::trace;
+
+/// Sums the first and last digit of each line, read as a two digit number.
+/// Spelled out digits don't count, so every line needs a numeral.
+pub fn calibrate(lines: &[String]) -> Result<u32, ParseError> {
+    let mut sum = 0;
+    for line in numbered(lines) {
+        let v: Vec<char> = line.text.chars().filter(|x| x.is_ascii_digit()).collect();
+        let (Some(first), Some(last)) = (v.first(), v.last()) else {
+            return Err(line.error(line.text, "part 1 needs a numeral on every line"));
+        };
+        trace!(target: "day01::calibrate", "Vec: {:?}", v);
+        let value = first.to_digit(10).unwrap() * 10 + last.to_digit(10).unwrap();
+        sum += value;
+    }
+    Ok(sum)
+}
+
+const PATTERNS: [&str; 10] = [
+    "IGNORED", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The calibration value of one line, where digits may also be spelled out.
+pub fn get_line_calibration(line: &str) -> u32 {
+    let mut first_index = line.len();
+    let mut first_index_end = line.len();
+    let mut last_index = 0;
+    let mut first_value = 0;
+    let mut last_value = 0;
+
+    if let Some(idx) = line.find(|c: char| c.is_ascii_digit()) {
+        first_index = idx;
+        first_index_end = idx;
+        first_value = line.chars().collect::<Vec<_>>()[idx].to_digit(10).unwrap();
+    }
+    if let Some(idx) = line.rfind(|c: char| c.is_ascii_digit()) {
+        last_index = idx;
+        last_value = line.chars().collect::<Vec<_>>()[idx].to_digit(10).unwrap();
+    }
+
+    let mut i = 1; // Skip the IGNORED zero entry
+    while i < PATTERNS.len() {
+        if let Some(idx) = line[..first_index_end].find(PATTERNS[i]) {
+            if idx < first_index {
+                first_index = idx;
+                first_index_end = idx + PATTERNS[i].len();
+                first_value = i as u32;
+            }
+        }
+        if let Some(idx) = line[last_index..].rfind(PATTERNS[i]) {
+            last_index += idx; // += since we sliced into the original string
+            last_value = i as u32;
+        }
+        i += 1;
+    }
+
+    let v = 10 * first_value + last_value;
+    trace!(target: "day01::calibrate", "line: {:?} v: {:?}", line, v);
+    v
+}
+
+// Every line needs at least one digit, as a numeral or spelled out.
+fn read_document(lines: &[String]) -> Result<Vec<String>, ParseError> {
+    for line in numbered(lines) {
+        let text = line.text;
+        if let Some((i, c)) = text
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_alphanumeric())
+        {
+            let token = &text[i..i + c.len_utf8()];
+            return Err(line.error(token, format!("unexpected character {:?}", c)));
+        }
+        if !text.contains(|c: char| c.is_ascii_digit())
+            && !PATTERNS[1..].iter().any(|p| text.contains(p))
+        {
+            return Err(line.error(text, "no digit on this line"));
+        }
+    }
+    Ok(lines.to_vec())
+}
+
+// Letters with digits and spelled out digits mixed in, always with at least
+// one real digit since part 1 needs it.
+fn random_document(rng: &mut Rng, lines: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..lines {
+        let pieces = rng.index(6) + 1;
+        let digit_at = rng.index(pieces);
+        for i in 0..pieces {
+            if i == digit_at || rng.chance(0.3) {
+                text.push(char::from_digit(rng.range(1..10) as u32, 10).unwrap());
+            } else if rng.chance(0.4) {
+                text += *rng.choose(&PATTERNS[1..]);
+            } else {
+                for _ in 0..rng.index(5) + 1 {
+                    text.push((b'a' + rng.below(26) as u8) as char);
+                }
+            }
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Sums [`get_line_calibration`] over every line.
+pub fn calibratetwo(lines: &[String]) -> u32 {
+    let mut sum = 0;
+    for line in lines {
+        sum += get_line_calibration(line);
+    }
+    sum
+}
+
+/// Day 1: Calibration values hidden in lines of text.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const PARTS: u8 = 2;
+
+    type Input = Vec<String>;
+    type Answer = Result<u32, SolveError>;
+    type Params = NoParams;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        read_document(lines)
+    }
+
+    fn part1(lines: &Self::Input) -> Self::Answer {
+        Ok(calibrate(lines)?)
+    }
+
+    fn part2(lines: &Self::Input) -> Self::Answer {
+        Ok(calibratetwo(lines))
+    }
+
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new().lines("[a-z0-9]+")
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(random_document(rng, 1000 * scale))
+    }
+}
@@ -0,0 +1,14 @@
+use aoc01::{calibrate, calibratetwo, Day01};
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day01>();
+}
+
+#[test]
+fn test_part1_needs_numerals() {
+    let lines: Vec<String> = ["1abc2", "onetwo"].map(String::from).to_vec();
+    let err = calibrate(&lines).unwrap_err();
+    assert_eq!((err.line, err.token.as_str()), (2, "onetwo"));
+    assert_eq!(calibratetwo(&lines), 12 + 12);
+}
@@ -0,0 +1,179 @@
+use aoc_common::params::Validate;
+use aoc_common::{numbered, Fingerprint, Line, ParseError, Pattern, Rng, Solution, SolveError};
+use serde::{Deserialize, Serialize};
+use tracing::trace;
+
+/// How many cubes of each color are in the bag for part 1.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub max_red: u32,
+    pub max_green: u32,
+    pub max_blue: u32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            max_red: 12,
+            max_green: 13,
+            max_blue: 14,
+        }
+    }
+}
+
+// Any count fits, even none of a color.
+impl Validate for Params {}
+
+/// The cubes shown in one handful.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Draw {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+/// A numbered game and each handful drawn in it.
+#[derive(Debug)]
+pub struct Game {
+    pub number: u32,
+    pub draws: Vec<Draw>,
+}
+
+const GAME: Pattern = Pattern::new("Game {number}: {draws}");
+const CUBES: Pattern = Pattern::new("{count} {color}");
+
+/// Parses `Game <n>: <draw>; <draw>; ...`, where each draw is a list of
+/// `<count> <color>`.
+pub fn parse_game(line: Line) -> Result<Game, ParseError> {
+    let game = GAME.parse_line(line)?;
+    let number = game.get::<u32>("number")?;
+
+    let mut draws = Vec::new();
+    for draw in game.str("draws").split(";") {
+        let mut shown = Draw::default();
+        for color in draw.split(",") {
+            let cubes = CUBES.parse(line, color)?;
+            trace!(target: "day02::parse", "cubes {:?}", cubes);
+            let count = match cubes.str("color") {
+                "red" => &mut shown.red,
+                "green" => &mut shown.green,
+                "blue" => &mut shown.blue,
+                _ => return Err(line.error(color.trim(), "Unknown color combination")),
+            };
+            *count = cubes.get::<u32>("count")?;
+        }
+        draws.push(shown);
+    }
+
+    Ok(Game { number, draws })
+}
+
+// Each draw shows one to three colours, sometimes more than the limits.
+fn random_games(rng: &mut Rng, games: usize) -> String {
+    let mut text = String::new();
+    for number in 1..=games {
+        let mut draws = Vec::new();
+        for _ in 0..rng.index(6) + 1 {
+            let mut colors = vec!["red", "green", "blue"];
+            rng.shuffle(&mut colors);
+            colors.truncate(rng.index(3) + 1);
+            let cubes: Vec<String> = colors
+                .iter()
+                .map(|color| CUBES.render(&[&rng.range(1..21), color]))
+                .collect();
+            draws.push(cubes.join(", "));
+        }
+        text += &GAME.render(&[&number, &draws.join("; ")]);
+        text.push('\n');
+    }
+    text
+}
+
+/// The game's number if every draw fits in the bag, otherwise 0.
+pub fn check_game(game: &Game, params: &Params) -> u32 {
+    for draw in &game.draws {
+        // Verify color maximums
+        if draw.red > params.max_red {
+            return 0;
+        }
+        if draw.green > params.max_green {
+            return 0;
+        }
+        if draw.blue > params.max_blue {
+            return 0;
+        }
+    }
+
+    game.number
+}
+
+/// Sums the numbers of the games that fit in the bag.
+pub fn check_games(games: &[Game], params: &Params) -> u64 {
+    games.iter().map(|g| u64::from(check_game(g, params))).sum()
+}
+
+/// The product of the fewest cubes of each color the game needs, or `None`
+/// if it overflows `u64`.
+pub fn power(game: &Game) -> Option<u64> {
+    let mut max_red = 0;
+    let mut max_green = 0;
+    let mut max_blue = 0;
+
+    for draw in &game.draws {
+        // Find color maximums
+        if draw.red > max_red {
+            max_red = draw.red;
+        }
+        if draw.green > max_green {
+            max_green = draw.green;
+        }
+        if draw.blue > max_blue {
+            max_blue = draw.blue;
+        }
+    }
+
+    u64::from(max_red)
+        .checked_mul(u64::from(max_green))?
+        .checked_mul(u64::from(max_blue))
+}
+
+/// Sums [`power`] over every game.
+pub fn sum_power(games: &[Game]) -> Result<u64, SolveError> {
+    games
+        .iter()
+        .try_fold(0u64, |sum, g| sum.checked_add(power(g)?))
+        .ok_or_else(|| SolveError::no_answer("the powers overflow u64"))
+}
+
+/// Day 2: Games of colored cubes drawn from a bag.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const PARTS: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Answer = Result<u64, SolveError>;
+    type Params = Params;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        numbered(lines).map(parse_game).collect()
+    }
+
+    fn part1(games: &Self::Input) -> Self::Answer {
+        Ok(check_games(games, &Self::params()))
+    }
+
+    fn part2(games: &Self::Input) -> Self::Answer {
+        sum_power(games)
+    }
+
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new().lines(r"Game \d+: \d+ (red|green|blue)([,;] \d+ (red|green|blue))*")
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(random_games(rng, 100 * scale))
+    }
+}
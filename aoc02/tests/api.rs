@@ -0,0 +1,25 @@
+use aoc02::Day02;
+use aoc_common::Solution;
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day02>();
+}
+
+#[test]
+fn test_bad_color() {
+    let lines = vec!["Game 1: 3 blue, 4 red; 1 purple".to_string()];
+    let err = Day02::parse(&lines).unwrap_err();
+    assert_eq!(
+        (err.line, err.column, err.token.as_str()),
+        (1, 24, "1 purple")
+    );
+}
+
+#[test]
+fn test_power_overflow() {
+    let game = "Game 1: 4000000000 red, 4000000000 green, 4000000000 blue\n";
+    let games = Day02::parse_str(game).unwrap();
+    assert_eq!(aoc02::power(&games[0]), None);
+    assert!(Day02::part2(&games).is_err());
+}
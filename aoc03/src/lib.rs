@@ -0,0 +1,257 @@
+use aoc_common::{numbered, Fingerprint, Grid, NoParams, ParseError, Rng, Solution, SolveError};
+use tracing::{debug, trace};
+
+/// A number in the schematic, and whether a symbol touches it.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Part {
+    number: u32,
+    row: usize,
+    col_start: usize,
+    col_end: usize,
+    valid: bool,
+}
+
+/// A symbol in the schematic. A `*` also keeps the product of the numbers
+/// touching it.
+#[derive(Clone, Debug)]
+pub struct Symbol {
+    is_star: bool,
+    gear_ratio: u64,
+    row: usize,
+    col: usize,
+}
+
+fn is_adjacent(part: &Part, sym: &Symbol) -> bool {
+    if part.row == sym.row {
+        // In the same row, you can only be directly to the left or right.
+        if part.col_start == sym.col + 1 || part.col_end + 1 == sym.col {
+            return true;
+        }
+    } else if part.row + 1 == sym.row || part.row == sym.row + 1 {
+        // In an adjacent row, we can be anywhere between one to the left,
+        // and one to the right of the columns.
+        let sc = sym.col;
+        let left = part.col_start;
+        let right = part.col_end;
+        if sc + 1 >= left && right + 1 >= sc {
+            return true;
+        }
+    }
+    false
+}
+
+fn update_parts(parts: &mut Vec<Part>, symbols: &Vec<Symbol>) {
+    for part in parts {
+        for sym in symbols {
+            if is_adjacent(part, sym) {
+                part.valid = true;
+                break;
+            }
+
+            if sym.row > part.row + 2 {
+                // Once we're 2 away on the symbols, we can't possibly validate
+                // more parts, so we skip the rest.
+                break;
+            }
+        }
+    }
+}
+
+fn read_schematic(lines: &[String]) -> Result<(Vec<Part>, Vec<Symbol>), ParseError> {
+    #[derive(PartialEq)]
+    enum ParseDigits {
+        Waiting,  // Waiting to read the start of a span of digits
+        Reading,  // Reading through a span of digits
+        Finished, // Finished reading a span (need to update and clean up)
+    }
+
+    // Everything other than digits and '.' is a symbol.
+    let schematic = Grid::parse(lines, |c| {
+        (c.is_ascii_punctuation() || c.is_ascii_digit()).then_some(c)
+    })?;
+    let mut parts: Vec<Part> = Vec::new();
+    let mut symbols: Vec<Symbol> = Vec::new();
+
+    for (row, line) in schematic.rows().enumerate() {
+        let mut state = ParseDigits::Waiting;
+        let mut p: Part = Default::default();
+        for (col, &c) in line.iter().enumerate() {
+            match c {
+                '.' => {
+                    if state == ParseDigits::Reading {
+                        state = ParseDigits::Finished;
+                    }
+                }
+                digit if c.is_ascii_digit() => {
+                    if state == ParseDigits::Waiting {
+                        state = ParseDigits::Reading;
+                        p = Part {
+                            number: 0,
+                            row,
+                            col_start: col,
+                            col_end: col,
+                            valid: false,
+                        };
+                    }
+                    p.col_end = col;
+                    let d = digit.to_digit(10).unwrap();
+                    p.number = match p.number.checked_mul(10).and_then(|n| n.checked_add(d)) {
+                        Some(n) => n,
+                        None => {
+                            // The grid is all ASCII, so columns are byte offsets.
+                            let line = numbered(lines).nth(row).unwrap();
+                            let digits = &line.text[p.col_start..=col];
+                            return Err(line.error(digits, "part number is too large"));
+                        }
+                    };
+                }
+                s => {
+                    if state == ParseDigits::Reading {
+                        state = ParseDigits::Finished;
+                    }
+                    let is_star = s == '*';
+                    let gear_ratio = 0;
+                    let symbol = Symbol {
+                        is_star,
+                        gear_ratio,
+                        row,
+                        col,
+                    };
+                    symbols.push(symbol);
+                }
+            }
+            if state == ParseDigits::Finished {
+                parts.push(p);
+                state = ParseDigits::Waiting;
+            }
+        }
+        if state == ParseDigits::Finished || state == ParseDigits::Reading {
+            parts.push(p);
+            //state = ParseDigits::Waiting;
+        }
+    }
+    Ok((parts, symbols))
+}
+
+// Numbers of up to three digits and symbols scattered over dots, with a good
+// share of stars so that some of them turn out to be gears.
+fn random_schematic(rng: &mut Rng, width: usize, height: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..height {
+        let mut row = String::new();
+        while row.len() < width {
+            let after_digit = row.ends_with(|c: char| c.is_ascii_digit());
+            if !after_digit && rng.chance(0.12) {
+                let len = (rng.index(3) + 1).min(width - row.len());
+                row += &rng.range(1..10).to_string();
+                for _ in 1..len {
+                    row += &rng.range(0..10).to_string();
+                }
+            } else if rng.chance(0.05) {
+                row.push(
+                    *rng.choose(&['*', '*', '*', '#', '+', '$', '/', '@', '%', '=', '&', '-']),
+                );
+            } else {
+                row.push('.');
+            }
+        }
+        text += &row;
+        text.push('\n');
+    }
+    text
+}
+
+/// The part numbers and symbols found in an engine schematic.
+pub struct Schematic {
+    parts: Vec<Part>,
+    symbols: Vec<Symbol>,
+}
+
+/// Sums the numbers next to any symbol.
+pub fn compute_part_sum(schematic: &Schematic) -> u64 {
+    let mut parts = schematic.parts.clone();
+    let symbols = &schematic.symbols;
+
+    update_parts(&mut parts, symbols);
+
+    debug!(target: "day03::parts", "Parts {:?}", parts);
+    debug!(target: "day03::parts", "Symbols {:?}", symbols);
+
+    let sum: u64 = parts
+        .iter()
+        .filter(|p| p.valid)
+        .map(|p| u64::from(p.number))
+        .sum();
+    sum
+}
+
+fn find_gears(parts: &[Part], symbols: &mut [Symbol]) {
+    // Gears have exactly 2 adjacent parts with a star symbol.
+    for sym in symbols.iter_mut().filter(|s| s.is_star) {
+        let mut found = 0;
+        let mut gear_components: Vec<u32> = Vec::new();
+        for part in parts {
+            if is_adjacent(part, sym) {
+                found += 1;
+                gear_components.push(part.number);
+            }
+            if found >= 3 {
+                break;
+            }
+        }
+        if found == 2 {
+            sym.gear_ratio = gear_components.iter().map(|&n| u64::from(n)).product();
+            trace!(target: "day03::gears", "Gear: {:?}", sym);
+        }
+    }
+}
+
+/// Sums the products of the two numbers next to each `*` that touches
+/// exactly two.
+pub fn sum_gear_ratios(schematic: &Schematic) -> Result<u64, SolveError> {
+    let mut parts = schematic.parts.clone();
+    let mut symbols = schematic.symbols.clone();
+    update_parts(&mut parts, &symbols);
+    find_gears(&parts, &mut symbols);
+
+    symbols
+        .iter()
+        .filter(|s| s.is_star)
+        .try_fold(0u64, |sum, s| sum.checked_add(s.gear_ratio))
+        .ok_or_else(|| SolveError::no_answer("the gear ratios overflow u64"))
+}
+
+/// Day 3: Part numbers and gears in an engine schematic.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const PARTS: u8 = 2;
+
+    type Input = Schematic;
+    type Answer = Result<u64, SolveError>;
+    type Params = NoParams;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        let (parts, symbols) = read_schematic(lines)?;
+        Ok(Schematic { parts, symbols })
+    }
+
+    fn part1(schematic: &Self::Input) -> Self::Answer {
+        Ok(compute_part_sum(schematic))
+    }
+
+    fn part2(schematic: &Self::Input) -> Self::Answer {
+        sum_gear_ratios(schematic)
+    }
+
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new()
+            .grid(r"[^\sA-Za-z]")
+            .containing("0123456789")
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(random_schematic(rng, 140, 140 * scale))
+    }
+}
@@ -0,0 +1,26 @@
+use aoc03::Day03;
+use aoc_common::Solution;
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day03>();
+}
+
+#[test]
+fn test_number_too_large() {
+    let err = Day03::parse_str("..4646468467*.\n").err().unwrap();
+    assert_eq!((err.column, err.token.as_str()), (3, "4646468467"));
+}
+
+#[test]
+fn test_gear_ratio_overflow() {
+    let schematic =
+        Day03::parse_str("4000000000*4000000000\n.....................\n4000000000*4000000000\n")
+            .unwrap();
+    assert_eq!(
+        Day03::part2(&schematic).unwrap_err().to_string(),
+        "error: no answer: the gear ratios overflow u64"
+    );
+    let schematic = Day03::parse_str("4000000000*4000000000\n").unwrap();
+    assert_eq!(Day03::part2(&schematic), Ok(16000000000000000000));
+}
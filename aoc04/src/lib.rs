@@ -0,0 +1,137 @@
+use aoc_common::{numbered, Fingerprint, NoParams, ParseError, Pattern, Rng, Solution, SolveError};
+use std::cmp::min;
+use std::collections::HashSet;
+
+const CARD: Pattern = Pattern::new("Card {id}: {wins} | {ours}");
+
+fn read_cards(lines: &[String]) -> Result<Vec<u32>, ParseError> {
+    let mut card_wins: Vec<u32> = Vec::new();
+
+    for line in numbered(lines) {
+        let card = CARD.parse_line(line)?;
+        card.get::<u32>("id")?;
+        let winners: HashSet<u32> = card.list("wins")?.into_iter().collect();
+
+        let mut score = 0;
+        for our_num in card.list::<u32>("ours")? {
+            if winners.contains(&our_num) {
+                score += 1;
+            }
+        }
+        card_wins.push(score);
+    }
+
+    Ok(card_wins)
+}
+
+fn number_list(numbers: &[u32]) -> String {
+    let toks: Vec<String> = numbers.iter().map(|n| format!("{:>2}", n)).collect();
+    toks.join(" ")
+}
+
+// Ten winning numbers and 25 of ours per card. Wins never reach past the last
+// card, as the puzzle promises. Each card's copies are added to the cards
+// after it, which grows exponentially, so the wins are also held back wherever
+// the copies would otherwise overflow part 2's total.
+fn random_cards(rng: &mut Rng, cards: usize) -> String {
+    let cap = u64::from(u32::MAX) / (2 * cards as u64).max(1);
+    let mut copies = vec![1; cards];
+    let mut text = String::new();
+    for i in 0..cards {
+        let mut wins = if rng.chance(0.5) { 0 } else { rng.index(11) };
+        wins = wins.min(cards - i - 1);
+        while (i + 1..min(i + 1 + wins, cards)).any(|j| copies[j] + copies[i] > cap) {
+            wins -= 1;
+        }
+        for j in i + 1..min(i + 1 + wins, cards) {
+            copies[j] += copies[i];
+        }
+
+        let mut numbers: Vec<u32> = (1..100).collect();
+        rng.shuffle(&mut numbers);
+        let winners = &numbers[..10];
+        let mut ours = numbers[10..35 - wins].to_vec();
+        ours.extend_from_slice(&winners[..wins]);
+        rng.shuffle(&mut ours);
+        let id = format!("{:>3}", i + 1);
+        text += &CARD.render(&[&id, &number_list(winners), &number_list(&ours)]);
+        text.push('\n');
+    }
+    text
+}
+
+fn overflow() -> SolveError {
+    SolveError::no_answer("the total overflows u32")
+}
+
+/// Each card is worth 1 point for its first match, doubled for each match
+/// after that. Takes the number of matches on each card.
+pub fn score_cards(card_wins: &[u32]) -> Result<u32, SolveError> {
+    card_wins
+        .iter()
+        .filter(|&&w| w != 0)
+        .try_fold(0u32, |total, &w| {
+            2_u32.checked_pow(w - 1)?.checked_add(total)
+        })
+        .ok_or_else(overflow)
+}
+
+/// How many cards we end up with when each match wins a copy of a
+/// following card. Wins that would reach past the last card are cut short
+/// there.
+pub fn copy_cards(card_wins: &[u32]) -> Result<u32, SolveError> {
+    let num_card_games = card_wins.len();
+
+    // Start with 1 of each card.
+    let mut cards: Vec<u32> = Vec::new();
+    for _i in 0..num_card_games {
+        cards.push(1);
+    }
+
+    for i in 0..num_card_games {
+        let wins = card_wins[i] as usize;
+        let limit = min(i + 1 + wins, num_card_games);
+        for j in i + 1..limit {
+            // We get n copies of the new cards.
+            cards[j] = cards[j].checked_add(cards[i]).ok_or_else(overflow)?;
+        }
+    }
+
+    cards
+        .iter()
+        .try_fold(0u32, |total, &n| total.checked_add(n))
+        .ok_or_else(overflow)
+}
+
+/// Day 4: Scratchcards and the copies they win.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const PARTS: u8 = 2;
+
+    // The number of winning numbers on each card.
+    type Input = Vec<u32>;
+    type Answer = Result<u32, SolveError>;
+    type Params = NoParams;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        read_cards(lines)
+    }
+
+    fn part1(card_wins: &Self::Input) -> Self::Answer {
+        score_cards(card_wins)
+    }
+
+    fn part2(card_wins: &Self::Input) -> Self::Answer {
+        copy_cards(card_wins)
+    }
+
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new().lines(r"Card +\d+:( +\d+)+ \|( +\d+)+")
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(random_cards(rng, 193 * scale))
+    }
+}
@@ -0,0 +1,15 @@
+use aoc04::{copy_cards, score_cards, Day04};
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day04>();
+}
+
+#[test]
+fn test_wins_past_the_end() {
+    assert_eq!(copy_cards(&[0, 0, 3]), Ok(3));
+    assert_eq!(copy_cards(&[2, 5, 0]), Ok(1 + 2 + 4));
+    assert!(copy_cards(&[40; 40]).is_err());
+    assert!(score_cards(&[40]).is_err());
+    assert_eq!(score_cards(&[0, 1, 4]), Ok(1 + 8));
+}
@@ -0,0 +1,181 @@
+use aoc_common::interval::{Interval, IntervalSet, RangeMap};
+use aoc_common::{numbered, Fingerprint, NoParams, ParseError, Rng, Solution, SolveError};
+use tracing::debug;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil map",
+    "soil-to-fertilizer map",
+    "fertilizer-to-water map",
+    "water-to-light map",
+    "light-to-temperature map",
+    "temperature-to-humidity map",
+    "humidity-to-location map",
+];
+
+/// The seeds to plant and the seven maps from seed through to location.
+pub struct Almanac {
+    pub seeds: Vec<i64>,
+    pub maps: Vec<RangeMap>,
+}
+
+fn read_almanac(lines: &[String]) -> Result<Almanac, ParseError> {
+    let mut seeds = Vec::new();
+    let mut seeds_seen = false;
+
+    let mut maps = Vec::new();
+    for line in numbered(lines) {
+        let toks: Vec<&str> = line.text.split(":").collect();
+        match toks[0] {
+            "seeds" if toks.len() > 1 => {
+                if seeds_seen {
+                    return Err(line.error(toks[0], "seeds are listed twice"));
+                }
+                seeds_seen = true;
+                // Read the seed numbers
+                let numbers: Vec<&str> = toks[1].split_whitespace().collect();
+                for n in &numbers {
+                    seeds.push(i64::from(line.parse::<u32>(n)?));
+                }
+                // Part 2 reads them as pairs of a start and a length.
+                match numbers.last() {
+                    None => return Err(line.error(line.text, "expected seed numbers")),
+                    Some(last) if numbers.len() % 2 == 1 => {
+                        return Err(line.error(last, "this seed has no length to pair it with"));
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            name if MAPS.contains(&name) => {
+                maps.push(RangeMap::new());
+            }
+
+            "" => {}
+
+            section if toks.len() > 1 => {
+                return Err(line.error(section, "unknown section"));
+            }
+
+            _ => {
+                // Read the digits in
+                let num_toks: Vec<&str> = line.text.split_whitespace().collect();
+                if num_toks.len() != 3 {
+                    return Err(line.error(line.text, "expected three numbers"));
+                }
+                let dest_start = i64::from(line.parse::<u32>(num_toks[0])?);
+                let src_start = i64::from(line.parse::<u32>(num_toks[1])?);
+                let range_len = i64::from(line.parse::<u32>(num_toks[2])?);
+                let source = Interval::from_len(src_start, range_len);
+                let Some(map) = maps.last_mut() else {
+                    return Err(line.error(line.text, "numbers before any map"));
+                };
+                if let Err(other) = map.insert(source, dest_start - src_start) {
+                    let message = format!("overlaps the range starting at {}", other.start);
+                    return Err(line.error(num_toks[1], message));
+                }
+            }
+        }
+    }
+
+    if !seeds_seen {
+        return Err(ParseError::missing(0, "expected a seeds line"));
+    }
+    debug!(target: "day05::parse", "seeds: {:?}", seeds);
+
+    Ok(Almanac { seeds, maps })
+}
+
+// Numbers are kept under 2^32, like the real ones and as parsing requires. Each map's source ranges
+// are cut from one sorted list of points so that they never overlap.
+fn random_almanac(rng: &mut Rng, seed_ranges: usize, ranges_per_map: usize) -> String {
+    const LIMIT: i64 = 1 << 32;
+    let seeds: Vec<String> = (0..seed_ranges)
+        .map(|_| {
+            let start = rng.range(0..LIMIT / 2);
+            format!("{} {}", start, rng.range(1..LIMIT / 8))
+        })
+        .collect();
+    let mut text = format!("seeds: {}\n", seeds.join(" "));
+    for name in MAPS {
+        text += &format!("\n{}:\n", name);
+        let mut points: Vec<i64> = (0..2 * ranges_per_map)
+            .map(|_| rng.range(0..LIMIT))
+            .collect();
+        points.sort();
+        let mut pieces: Vec<&[i64]> = points.chunks(2).collect();
+        rng.shuffle(&mut pieces);
+        for piece in pieces {
+            let len = piece[1] - piece[0];
+            if len > 0 {
+                let dest = rng.range(0..LIMIT - len);
+                text += &format!("{} {} {}\n", dest, piece[0], len);
+            }
+        }
+    }
+    text
+}
+
+/// The lowest location any seed maps to. With `use_seed_ranges`, the seeds
+/// are read in pairs as a start and a length.
+pub fn get_lowest_location(almanac: &Almanac, use_seed_ranges: bool) -> i64 {
+    let seeds = &almanac.seeds;
+    let maps = &almanac.maps;
+
+    if use_seed_ranges {
+        // Push whole ranges through each map, splitting them wherever the
+        // map's pieces start and stop, rather than one seed at a time.
+        let mut locs: IntervalSet = seeds
+            .chunks_exact(2)
+            .map(|pair| Interval::from_len(pair[0], pair[1]))
+            .collect();
+        for map in maps {
+            locs = map.map_set(&locs);
+            debug!(target: "day05::seeds", "{} ranges after map", locs.iter().count());
+        }
+        locs.min().unwrap_or(i64::MAX)
+    } else {
+        seeds
+            .iter()
+            .map(|&seed| maps.iter().fold(seed, |loc, map| map.map(loc)))
+            .min()
+            .unwrap_or(i64::MAX)
+    }
+}
+
+/// Day 5: Seeds mapped through the almanac to locations.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const PARTS: u8 = 2;
+
+    type Input = Almanac;
+    type Answer = Result<i64, SolveError>;
+    type Params = NoParams;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        read_almanac(lines)
+    }
+
+    fn part1(almanac: &Self::Input) -> Self::Answer {
+        Ok(get_lowest_location(almanac, false))
+    }
+
+    fn part2(almanac: &Self::Input) -> Self::Answer {
+        Ok(get_lowest_location(almanac, true))
+    }
+
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new().line(r"seeds:( \d+)+").repeat(
+            MAPS.len(),
+            Fingerprint::new()
+                .blank()
+                .line("[a-z]+-to-[a-z]+ map:")
+                .lines(r"\d+ \d+ \d+"),
+        )
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(random_almanac(rng, 10 * scale, 25 * scale))
+    }
+}
@@ -0,0 +1,21 @@
+use aoc05::Day05;
+use aoc_common::Solution;
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day05>();
+}
+
+#[test]
+fn test_bad_almanac() {
+    assert!(Day05::parse_str("seeds\n").is_err());
+    let err = Day05::parse_str("seeds: 1 2\n\nseed-to-soil map:\n9223372036854775807 0 9\n")
+        .err()
+        .unwrap();
+    assert_eq!((err.line, err.column), (4, 1));
+
+    let err = Day05::parse_str("seeds: 79 14 55\n").err().unwrap();
+    assert_eq!((err.line, err.column, err.token.as_str()), (1, 14, "55"));
+    assert!(Day05::parse_str("seeds: 1 2\nseeds: 3 4\n").is_err());
+    assert!(Day05::parse_str("seed-to-soil map:\n1 2 3\n").is_err());
+}
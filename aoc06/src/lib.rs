@@ -0,0 +1,161 @@
+use aoc_common::{numbered, Fingerprint, NoParams, ParseError, Rng, Solution, SolveError};
+use tracing::debug;
+
+/// How many ways there are to hold the button and beat the record distance.
+pub fn compute_wins(time: u64, distance: u64) -> u64 {
+    let beats = |hold: u64| u128::from(hold) * u128::from(time - hold) > u128::from(distance);
+    // The distance peaks at half the time and falls away evenly either side,
+    // so the winning holds run from the shortest one up to time minus it.
+    let (mut shortest, mut longest) = (0, time / 2);
+    if !beats(longest) {
+        return 0;
+    }
+    while shortest < longest {
+        let mid = shortest + (longest - shortest) / 2;
+        if beats(mid) {
+            longest = mid;
+        } else {
+            shortest = mid + 1;
+        }
+    }
+    time - 2 * shortest + 1
+}
+
+/// The time allowed and the record distance for each race.
+#[derive(Debug)]
+pub struct Races {
+    pub times: Vec<u64>,
+    pub distances: Vec<u64>,
+}
+
+fn read_races(lines: &[String]) -> Result<Races, ParseError> {
+    let mut race_times: Option<Vec<u64>> = None;
+    let mut race_distances: Option<Vec<u64>> = None;
+
+    for line in numbered(lines) {
+        let (name, values) = line.split_once(line.text, ":")?;
+        let numbers = match name {
+            "Time" => &mut race_times,
+            "Distance" => &mut race_distances,
+            _ => {
+                return Err(line.error(name, "Unknown parse for line"));
+            }
+        };
+        if numbers.is_some() {
+            return Err(line.error(name, format!("more than one {} line", name)));
+        }
+        let toks: Vec<&str> = values.split_whitespace().collect();
+        if toks.is_empty() {
+            return Err(line.error(line.text, "expected at least one race"));
+        }
+        let values = toks.iter().map(|tok| line.parse::<u64>(tok));
+        *numbers = Some(values.collect::<Result<_, _>>()?);
+    }
+
+    let last = lines.len().saturating_sub(1);
+    let (Some(race_times), Some(race_distances)) = (race_times, race_distances) else {
+        return Err(ParseError::missing(
+            last,
+            "expected a Time and a Distance line",
+        ));
+    };
+    if race_times.len() != race_distances.len() {
+        return Err(ParseError::missing(
+            last,
+            format!(
+                "{} times but {} distances",
+                race_times.len(),
+                race_distances.len()
+            ),
+        ));
+    }
+
+    debug!(target: "day06::parse", "race_times: {:?}", race_times);
+    debug!(target: "day06::parse", "race_distances: {:?}", race_distances);
+
+    Ok(Races {
+        times: race_times,
+        distances: race_distances,
+    })
+}
+
+// Four races that can each be won somehow. Part 2 joins each line's numbers
+// into one, so any more races would overflow it.
+fn random_races(rng: &mut Rng) -> String {
+    let mut times = String::new();
+    let mut distances = String::new();
+    for _ in 0..4 {
+        let time = rng.range(7..60);
+        let best = (time / 2) * (time - time / 2);
+        times += &format!("{:>7}", time);
+        distances += &format!("{:>7}", rng.range(0..best));
+    }
+    format!("Time:    {}\nDistance:{}\n", times, distances)
+}
+
+/// The product of the ways to win each race.
+pub fn compute_racing_records(races: &Races) -> Result<u64, SolveError> {
+    let mut records: u64 = 1;
+    for (&time, &distance) in races.times.iter().zip(&races.distances) {
+        let wins = compute_wins(time, distance);
+        records = records
+            .checked_mul(wins)
+            .ok_or_else(|| SolveError::no_answer("the product overflows u64"))?;
+    }
+
+    Ok(records)
+}
+
+// The bad kerning means all of the numbers on a line are really one number.
+fn kern(values: &[u64]) -> Result<u64, SolveError> {
+    let kerned: String = values.iter().map(|v| v.to_string()).collect();
+    kerned
+        .parse::<u64>()
+        .map_err(|_| SolveError::no_answer(format!("{} overflows u64", kerned)))
+}
+
+/// The ways to win the one long race you get by ignoring the spaces.
+pub fn compute_racing_records_kerned(races: &Races) -> Result<u64, SolveError> {
+    let time = kern(&races.times)?;
+    let distance = kern(&races.distances)?;
+
+    debug!(target: "day06::kern", "time: {}", time);
+    debug!(target: "day06::kern", "distance: {}", distance);
+
+    Ok(compute_wins(time, distance))
+}
+
+/// Day 6: Boat races against the record distances.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const PARTS: u8 = 2;
+
+    type Input = Races;
+    type Answer = Result<u64, SolveError>;
+    type Params = NoParams;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        read_races(lines)
+    }
+
+    fn part1(races: &Self::Input) -> Self::Answer {
+        compute_racing_records(races)
+    }
+
+    fn part2(races: &Self::Input) -> Self::Answer {
+        compute_racing_records_kerned(races)
+    }
+
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new()
+            .line(r"Time:( +\d+)+")
+            .line(r"Distance:( +\d+)+")
+    }
+
+    // There's no way to scale this one up.
+    fn generate(rng: &mut Rng, _scale: usize) -> Option<String> {
+        Some(random_races(rng))
+    }
+}
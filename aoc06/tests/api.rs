@@ -0,0 +1,34 @@
+use aoc06::{compute_racing_records_kerned, compute_wins, Day06};
+use aoc_common::Solution;
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day06>();
+}
+
+#[test]
+fn test_bad_line() {
+    let lines = vec!["Time: 7 15".to_string(), "Distanse: 9 40".to_string()];
+    let err = Day06::parse(&lines).unwrap_err();
+    assert_eq!(
+        (err.line, err.column, err.token.as_str()),
+        (2, 1, "Distanse")
+    );
+}
+
+#[test]
+fn test_one_line_each() {
+    let err = Day06::parse_str("Time: 7\nDistance: 9\nTime: 15\n").unwrap_err();
+    assert_eq!(
+        (err.line, err.message.as_str()),
+        (3, "more than one Time line")
+    );
+    assert!(Day06::parse_str("Time: 7 15\n").is_err());
+    assert!(Day06::parse_str("Time:\nDistance:\n").is_err());
+
+    let races = Day06::parse_str("Time: 99999 99999 99999 99999\nDistance: 1 1 1 1\n").unwrap();
+    assert!(compute_racing_records_kerned(&races).is_err());
+    assert_eq!(compute_wins(30, 200), 9);
+    assert_eq!(compute_wins(2, 1), 0);
+    assert!(compute_wins(u64::MAX, u64::MAX) > 0);
+}
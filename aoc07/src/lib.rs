@@ -0,0 +1,324 @@
+use aoc_common::{numbered, Fingerprint, NoParams, ParseError, Rng, Solution, SolveError};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use tracing::trace;
+
+/// Hand types, weakest first.
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum HandType {
+    HighCard = 0,
+    OnePair = 1,
+    TwoPair = 2,
+    ThreeOfAKind = 3,
+    FullHouse = 4,
+    FourOfAKind = 5,
+    FiveOfAKind = 6,
+}
+
+/// A hand of five cards and its bid. Hands order by type and then card by
+/// card from the left, then by bid, which only matters for two hands with the
+/// same cards; `parse` turns down an input that lists a hand twice.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Hand {
+    pub hand_type: HandType,
+    high_cards: [usize; 5],
+    pub bid: u64,
+    hand_str: String,
+    card_vals_in_string_order: [usize; 5],
+}
+
+impl Hand {
+    /// With `jokers`, J is the weakest card but stands in for whatever makes
+    /// the best hand. `hand_str` has to be five of the cards 2-9, T, J, Q, K
+    /// and A, as `parse` checks.
+    pub fn new(hand_str: &str, bid: u64, jokers: bool) -> Self {
+        // Just zero initialize everything
+        let mut hand = Hand {
+            hand_type: HandType::HighCard,
+            high_cards: [0; 5],
+            bid,
+            hand_str: hand_str.to_string(),
+            card_vals_in_string_order: [0; 5],
+        };
+
+        // Just use simple 15 element array since J-A get marked as 11-14,
+        // and we don't care about the nonexistent 0 and 1 entries.
+        // Later we use 1 for Jokers.
+        let mut card: [u8; 15] = [0; 15];
+        let mut val;
+        for (i, c) in hand_str.chars().enumerate() {
+            match c {
+                'T' => {
+                    val = 10;
+                }
+                'J' => {
+                    if jokers {
+                        val = 1;
+                    } else {
+                        val = 11;
+                    }
+                }
+                'Q' => {
+                    val = 12;
+                }
+                'K' => {
+                    val = 13;
+                }
+                'A' => {
+                    val = 14;
+                }
+                '2'..='9' => {
+                    val = c as usize - '0' as usize;
+                }
+                _ => {
+                    unreachable!("unknown card '{}'", c);
+                }
+            }
+            card[val] += 1;
+            hand.card_vals_in_string_order[i] = val;
+        }
+
+        // No longer needs to be mutable.
+        let card = card;
+        let num_jokers = card[1];
+
+        // Search for hands in decreasing order of importance. Whatever a hand
+        // is, five cards always leave enough others to fill it out, so the
+        // searches for them below can't come up empty.
+        for i in (2..15).rev() {
+            if card[i] + num_jokers == 5 {
+                hand.hand_type = HandType::FiveOfAKind;
+                hand.high_cards[0] = i;
+                return hand;
+            }
+        }
+
+        for i in (2..15).rev() {
+            if card[i] + num_jokers == 4 {
+                hand.hand_type = HandType::FourOfAKind;
+                hand.high_cards[0] = i;
+                for j in (2..15).rev() {
+                    if card[j] == 1 {
+                        hand.high_cards[1] = j;
+                        return hand;
+                    }
+                }
+                unreachable!("4 of a kind with no other cards: {}", hand_str);
+            }
+        }
+
+        for i in (2..15).rev() {
+            if card[i] + num_jokers == 3 {
+                hand.high_cards[0] = i;
+                for j in (2..15).rev() {
+                    if card[j] == 2 && j != i {
+                        hand.hand_type = HandType::FullHouse;
+                        hand.high_cards[1] = j;
+                        return hand;
+                    } else if card[j] == 1 {
+                        hand.hand_type = HandType::ThreeOfAKind;
+                        hand.high_cards[1] = j;
+                        for k in (2..15).rev() {
+                            if card[k] == 1 && k != j {
+                                hand.high_cards[2] = k;
+                                return hand;
+                            }
+                        }
+                        unreachable!("missing a 5th card for 3 of a kind: {}", hand_str);
+                    }
+                }
+                unreachable!("3 of a kind with no other cards: {}", hand_str);
+            }
+        }
+
+        // Two pair and One pair
+        for i in (2..15).rev() {
+            if card[i] + num_jokers == 2 {
+                let mut next_high_card = 0;
+                hand.hand_type = HandType::OnePair;
+                hand.high_cards[next_high_card] = i;
+                next_high_card += 1;
+                for j in (2..15).rev() {
+                    if card[j] == 2 && j != i {
+                        hand.hand_type = HandType::TwoPair;
+                        hand.high_cards[1] = j;
+                        for k in (2..15).rev() {
+                            if card[k] == 1 {
+                                hand.high_cards[2] = k;
+                                return hand;
+                            }
+                        }
+                        unreachable!("missing a 5th card for 2 pair: {}", hand_str);
+                    } else if card[j] == 1 {
+                        hand.high_cards[next_high_card] = j;
+                        next_high_card += 1;
+                        if next_high_card == 4 {
+                            return hand;
+                        }
+                    }
+                }
+                unreachable!("1 pair with not enough other cards: {}", hand_str);
+            }
+        }
+
+        // We can't have any jokers if we reach this point.
+        assert_eq!(num_jokers, 0);
+
+        // High card
+        let mut next_high_card = 0;
+        hand.hand_type = HandType::HighCard;
+        for i in (2..15).rev() {
+            if card[i] == 1 {
+                hand.high_cards[next_high_card] = i;
+                next_high_card += 1;
+            }
+        }
+        assert_eq!(next_high_card, 5);
+
+        hand
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        //self.height.cmp(&other.height)
+        if self.hand_type > other.hand_type {
+            return Ordering::Greater;
+        } else if self.hand_type < other.hand_type {
+            return Ordering::Less;
+        }
+        assert_eq!(self.hand_type, other.hand_type);
+
+        /* Don't compare actual hands. It only wants the order of the cards!
+        for i in 0..5 {
+            if self.high_cards[i] > other.high_cards[i] {
+                return Ordering::Greater;
+            } else if self.high_cards[i] < other.high_cards[i] {
+                return Ordering::Less;
+            }
+        }
+        */
+        for i in 0..5 {
+            if self.card_vals_in_string_order[i] > other.card_vals_in_string_order[i] {
+                return Ordering::Greater;
+            } else if self.card_vals_in_string_order[i] < other.card_vals_in_string_order[i] {
+                return Ordering::Less;
+            }
+        }
+
+        // Everything else follows from the cards, so this keeps the order
+        // in line with ==.
+        self.bid.cmp(&other.bid)
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Each hand's cards and bid, which are only turned into a Hand once we know
+// whether J is a jack or a joker.
+fn read_bids(lines: &[String]) -> Result<Vec<(String, u64)>, ParseError> {
+    let mut bids = Vec::new();
+    let mut seen = HashMap::new();
+
+    for line in numbered(lines) {
+        let toks: Vec<_> = line.text.split_whitespace().collect();
+        if toks.len() != 2 {
+            return Err(line.error(line.text, "expected a hand and a bid"));
+        }
+        let hand_str = toks[0];
+        if let Some((i, c)) = hand_str
+            .char_indices()
+            .find(|&(_, c)| !"23456789TJQKA".contains(c))
+        {
+            let token = &hand_str[i..i + c.len_utf8()];
+            return Err(line.error(token, format!("unknown card '{}'", c)));
+        }
+        if hand_str.len() != 5 {
+            return Err(line.error(hand_str, "expected five cards"));
+        }
+        if let Some(first) = seen.insert(hand_str, line.index + 1) {
+            let message = format!("the same hand as line {}", first);
+            return Err(line.error(hand_str, message));
+        }
+        let bid: u64 = line.parse(toks[1])?;
+        bids.push((hand_str.to_string(), bid));
+    }
+
+    Ok(bids)
+}
+
+// No two hands can be the same, since they'd have no order. Past half of the
+// 13^5 possible hands picking new ones gets slow, so that's the most we make.
+fn random_bids(rng: &mut Rng, hands: usize) -> String {
+    let cards: Vec<char> = "23456789TJQKA".chars().collect();
+    let hands = hands.min(13usize.pow(5) / 2);
+    let mut seen = HashSet::new();
+    let mut text = String::new();
+    while seen.len() < hands {
+        let hand: String = (0..5).map(|_| *rng.choose(&cards)).collect();
+        if seen.insert(hand.clone()) {
+            text += &format!("{} {}\n", hand, rng.range(1..1001));
+        }
+    }
+    text
+}
+
+/// The total of each bid times its hand's rank.
+pub fn compute_winnings(bids: &[(String, u64)], jokers: bool) -> Result<u64, SolveError> {
+    let mut hands = Vec::new();
+
+    for (hand_str, bid) in bids {
+        let hand = Hand::new(hand_str, *bid, jokers);
+        trace!(target: "day07::hands", "hand: {:?}", hand);
+        hands.push(hand);
+    }
+
+    hands.sort();
+
+    let mut winnings: u64 = 0;
+    for (rank, hand) in hands.iter().enumerate() {
+        trace!(target: "day07::rank", "hand {:3}: {:?}", rank, hand);
+        winnings = (rank as u64 + 1)
+            .checked_mul(hand.bid)
+            .and_then(|won| winnings.checked_add(won))
+            .ok_or_else(|| SolveError::no_answer("the winnings overflow u64"))?;
+    }
+
+    Ok(winnings)
+}
+
+/// Day 7: Camel Cards hands ranked by type and then card by card.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const PARTS: u8 = 2;
+
+    type Input = Vec<(String, u64)>;
+    type Answer = Result<u64, SolveError>;
+    type Params = NoParams;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        read_bids(lines)
+    }
+
+    fn part1(bids: &Self::Input) -> Self::Answer {
+        compute_winnings(bids, false)
+    }
+
+    fn part2(bids: &Self::Input) -> Self::Answer {
+        compute_winnings(bids, true)
+    }
+
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new().lines(r"[2-9TJQKA]{5} \d+")
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(random_bids(rng, 1000 * scale))
+    }
+}
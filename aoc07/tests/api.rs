@@ -0,0 +1,43 @@
+use aoc07::{Day07, Hand, HandType};
+use aoc_common::Solution;
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day07>();
+}
+
+#[test]
+fn test_hand_order() {
+    let pair = Hand::new("32T3K", 765, false);
+    let two_pair = Hand::new("KK677", 28, false);
+    assert_eq!(pair.hand_type, HandType::OnePair);
+    assert!(two_pair > pair);
+    // Ties on type go by the first card that differs, not the best one.
+    assert!(Hand::new("KTJJT", 220, false) < two_pair);
+    assert_eq!(
+        Hand::new("KTJJT", 220, true).hand_type,
+        HandType::FourOfAKind
+    );
+}
+
+#[test]
+fn test_unknown_card() {
+    let lines = vec!["32T3K 765".to_string(), "T5X55 684".to_string()];
+    let err = Day07::parse(&lines).unwrap_err();
+    assert_eq!((err.line, err.column, err.token.as_str()), (2, 3, "X"));
+    assert!(err.to_string().contains("unknown card 'X'"));
+}
+
+#[test]
+fn test_duplicate_hand() {
+    let lines = vec!["32T3K 765".to_string(), "32T3K 684".to_string()];
+    let err = Day07::parse(&lines).unwrap_err();
+    assert_eq!((err.line, err.token.as_str()), (2, "32T3K"));
+    assert!(err.to_string().contains("the same hand as line 1"));
+
+    let lines = vec![
+        "32T3K 18446744073709551615".to_string(),
+        "KK677 2".to_string(),
+    ];
+    assert!(Day07::part1(&Day07::parse(&lines).unwrap()).is_err());
+}
@@ -0,0 +1,368 @@
+use aoc_common::cycle::{self, Cycle};
+use aoc_common::graph::{bfs, Graph};
+use aoc_common::math::{crt, MathError};
+use aoc_common::{
+    metrics, numbered, Fingerprint, Line, NoParams, ParseError, Pattern, Rng, Solution, SolveError,
+};
+use std::collections::HashMap;
+use tracing::{debug, trace};
+
+type Node = [char; 3];
+
+trait ToNode {
+    fn to_node(&self) -> Node;
+}
+
+impl ToNode for str {
+    fn to_node(&self) -> Node {
+        self.chars().collect::<Vec<_>>().try_into().unwrap()
+    }
+}
+
+/// The left/right instructions and the left and right node out of each
+/// node.
+pub struct Network {
+    directions: Vec<usize>,
+    netmap: HashMap<Node, [Node; 2]>,
+}
+
+const NODE: Pattern = Pattern::new("{node} = ({left}, {right})");
+
+fn read_node(line: Line, tok: &str) -> Result<Node, ParseError> {
+    if tok.len() != 3 || !tok.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(line.error(tok, "expected a three character node"));
+    }
+    Ok(tok.to_node())
+}
+
+impl Network {
+    fn new(lines: &[String]) -> Result<Self, ParseError> {
+        let mut lines = numbered(lines);
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::missing(0, "expected directions"))?;
+        let mut directions = Vec::new();
+        for (i, c) in line.text.char_indices() {
+            match c {
+                'L' => directions.push(0),
+                'R' => directions.push(1),
+                _ => {
+                    let token = &line.text[i..i + c.len_utf8()];
+                    return Err(line.error(token, format!("unknown direction {:?}", c)));
+                }
+            }
+        }
+        if directions.is_empty() {
+            return Err(line.error(line.text, "expected directions"));
+        }
+
+        let mut netmap = HashMap::new();
+        let mut targets = Vec::new();
+        for line in lines.filter(|l| !l.text.is_empty()) {
+            let caps = NODE.parse_line(line)?;
+            let node: Node = read_node(line, caps.str("node"))?;
+            let (left_tok, right_tok) = (caps.str("left"), caps.str("right"));
+            let left = read_node(line, left_tok)?;
+            let right = read_node(line, right_tok)?;
+            targets.push((line, left_tok, left));
+            targets.push((line, right_tok, right));
+
+            let leftright = [left, right];
+            netmap.insert(node, leftright);
+        }
+
+        // Every step has to land somewhere.
+        for (line, tok, node) in targets {
+            if !netmap.contains_key(&node) {
+                return Err(line.error(tok, "node is never defined"));
+            }
+        }
+        debug!(target: "day08::network", "directions {:?}", directions);
+        trace!(target: "day08::network", "netmap {:?}", netmap);
+        Ok(Self { directions, netmap })
+    }
+
+    // Where we are depends on which direction comes next as well as the node.
+    fn step(&self, (node, dir_ctr): (Node, usize)) -> (Node, usize) {
+        let left_or_right = self.directions[dir_ctr];
+        let next = self.netmap[&node][left_or_right];
+        (next, (dir_ctr + 1) % self.directions.len())
+    }
+}
+
+impl Graph for Network {
+    type Node = (Node, usize);
+
+    fn neighbours(&self, state: Self::Node) -> impl Iterator<Item = (Self::Node, u64)> {
+        std::iter::once((self.step(state), 1))
+    }
+}
+
+// Shaped like the real networks: each ghost runs around its own ring, where
+// every step moves one place on whichever way it turns, so it's on its Z node
+// every len steps. The ring lengths are distinct primes times a shared factor
+// that grows with the scale, which keeps the answer from overflowing. Three
+// character names run out at around 50 times the real size.
+fn random_network(rng: &mut Rng, scale: usize) -> String {
+    const CHARS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let name = |a: u8, b: u8, c: u8| String::from_utf8(vec![a, b, c]).unwrap();
+    let mut middles = Vec::new();
+    let mut ends = Vec::new();
+    for &a in CHARS {
+        for &b in CHARS {
+            ends.push((name(a, b, b'A'), name(a, b, b'Z')));
+            for &c in CHARS.iter().filter(|&&c| c != b'A' && c != b'Z') {
+                middles.push(name(a, b, c));
+            }
+        }
+    }
+    rng.shuffle(&mut middles);
+    ends.retain(|end| end.0 != "AAA");
+    rng.shuffle(&mut ends);
+    ends.insert(0, ("AAA".to_string(), "ZZZ".to_string()));
+
+    let mut primes = [37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79];
+    rng.shuffle(&mut primes);
+    let primes = &primes[..6];
+    let per_factor = 2 * primes.iter().sum::<usize>();
+    let factor = scale.clamp(1, middles.len() / per_factor);
+
+    let mut nodes = Vec::new();
+    for (&prime, (start, end)) in primes.iter().zip(ends) {
+        let len = prime * factor;
+        let ring: Vec<[String; 2]> = (1..len)
+            .map(|_| [middles.pop().unwrap(), middles.pop().unwrap()])
+            .collect();
+        nodes.push((start, ring[0].clone()));
+        for pair in ring.windows(2) {
+            nodes.push((pair[0][0].clone(), pair[1].clone()));
+            nodes.push((pair[0][1].clone(), pair[1].clone()));
+        }
+        let last = &ring[ring.len() - 1];
+        nodes.push((last[0].clone(), [end.clone(), end.clone()]));
+        nodes.push((last[1].clone(), [end.clone(), end.clone()]));
+        nodes.push((end, ring[0].clone()));
+    }
+    rng.shuffle(&mut nodes);
+
+    let directions: String = (0..263)
+        .map(|_| if rng.chance(0.5) { 'L' } else { 'R' })
+        .collect();
+    let mut text = format!("{}\n\n", directions);
+    for (node, [left, right]) in nodes {
+        text += &NODE.render(&[&node, &left, &right]);
+        text.push('\n');
+    }
+    text
+}
+
+/// Steps from AAA to ZZZ following the instructions.
+pub fn compute_steps(network: &Network) -> Result<usize, SolveError> {
+    let start: Node = "AAA".to_node();
+    let stop: Node = "ZZZ".to_node();
+    if !network.netmap.contains_key(&start) {
+        return Err(SolveError::no_answer("there's no AAA node"));
+    }
+    let path = bfs(network, (start, 0), |(node, _)| node == stop)
+        .ok_or_else(|| SolveError::no_answer("ZZZ is never reached from AAA"))?;
+    Ok(path.len() - 1)
+}
+
+// When a ghost is on a Z node: at each of the `once` times, and then at start
+// + offset + k * period for every offset and every k >= 0. A ghost that stops
+// passing Z nodes has no offsets.
+#[derive(Debug)]
+struct Schedule {
+    once: Vec<usize>,
+    start: usize,
+    period: usize,
+    offsets: Vec<usize>,
+}
+
+impl Schedule {
+    fn hits(&self, t: usize) -> bool {
+        self.once.contains(&t)
+            || (!self.offsets.is_empty()
+                && t >= self.start
+                && self
+                    .offsets
+                    .binary_search(&((t - self.start) % self.period))
+                    .is_ok())
+    }
+}
+
+// Steps from a state to the next state on a Z node, and that state. There are
+// only so many states, so a walk that outlasts them all has gone round
+// without finding one.
+fn next_z(network: &Network, mut state: (Node, usize)) -> Option<(usize, (Node, usize))> {
+    let limit = network.netmap.len() * network.directions.len();
+    for steps in 1..=limit {
+        state = network.step(state);
+        if state.0[2] == 'Z' {
+            return Some((steps, state));
+        }
+    }
+    None
+}
+
+// Jumping from one Z node to the next has to repeat too, and there are far
+// fewer of those states than of the whole walk.
+fn find_schedule(network: &Network, ghost: &Node) -> Option<Schedule> {
+    let (first, state) = next_z(network, (*ghost, 0))?;
+    let mut gaps = Vec::new();
+    // None once the ghost has passed its last Z node.
+    let history = cycle::hashed(Some(state), |&state| {
+        let (steps, next) = next_z(network, state?)?;
+        gaps.push(steps);
+        Some(next)
+    });
+    let Cycle { tail, len } = history.cycle;
+
+    // times[i] is when the ghost gets to states[i], and times[tail + len] is
+    // when it's back at states[tail].
+    let times: Vec<usize> = std::iter::once(first)
+        .chain(gaps.iter().scan(first, |t, gap| {
+            *t += gap;
+            Some(*t)
+        }))
+        .collect();
+    if history.states[tail].is_none() {
+        return Some(Schedule {
+            once: times,
+            start: 0,
+            period: 0,
+            offsets: Vec::new(),
+        });
+    }
+    let start = times[tail];
+    let mut period = times[tail + len] - start;
+    let mut offsets: Vec<usize> = times[tail..tail + len].iter().map(|t| t - start).collect();
+    // Evenly spaced Z nodes make a shorter loop, whatever the directions do.
+    if gaps[tail..].iter().all(|&gap| gap == gaps[tail]) {
+        period = gaps[tail];
+        offsets = vec![0];
+    }
+
+    let name: String = ghost.iter().collect();
+    metrics::record(&format!("loop_count.{}", name), start as i64);
+    metrics::record(&format!("loop_period.{}", name), period as i64);
+    metrics::record("dir_len", network.directions.len() as i64);
+    Some(Schedule {
+        once: times[..tail].to_vec(),
+        start,
+        period,
+        offsets,
+    })
+}
+
+// Ways for the ghosts so far to be on Z nodes together, past which merging in
+// the next one is too slow to be worth it.
+const MAX_LINEUPS: usize = 1 << 20;
+
+/// Steps until ghosts starting on every node ending in A are all on nodes
+/// ending in Z at once.
+pub fn compute_ghost_steps(network: &Network) -> Result<usize, SolveError> {
+    let mut nodes: Vec<&Node> = network.netmap.keys().filter(|k| k[2] == 'A').collect();
+    nodes.sort();
+    if nodes.is_empty() {
+        return Err(SolveError::no_answer("there are no nodes ending in A"));
+    }
+
+    debug!(target: "day08::ghosts", "nodes: {:?}", nodes);
+    let mut schedules = Vec::new();
+    for node in nodes {
+        let schedule = find_schedule(network, node).ok_or_else(|| {
+            let name: String = node.iter().collect();
+            SolveError::no_answer(format!("the ghost from {} never reaches a Z node", name))
+        })?;
+        schedules.push(schedule);
+    }
+    debug!(target: "day08::ghosts", "schedules: {:?}", schedules);
+
+    // Before the last ghost settles into its loop, it can only be on a Z node
+    // at one of its one-off times.
+    let early = schedules
+        .iter()
+        .flat_map(|s| &s.once)
+        .filter(|&&t| schedules.iter().all(|s| s.hits(t)))
+        .min();
+    if let Some(&t) = early {
+        return Ok(t);
+    }
+
+    // Otherwise it's the first time every ghost's congruence holds, for some
+    // choice of offset from each. Merging them a ghost at a time drops the
+    // choices that can never agree, and the ones that agree on the same times,
+    // so there are never more than the combined period has room for.
+    let overflow = || SolveError::no_answer("the step count overflows usize");
+    let latest = schedules.iter().map(|s| s.start).max().unwrap_or(0) as i128;
+    let mut merged: Vec<(i128, i128)> = vec![(0, 1)];
+    for s in &schedules {
+        let mut next = Vec::new();
+        for &so_far in &merged {
+            for &offset in &s.offsets {
+                let ghost = ((s.start + offset) as i128, s.period as i128);
+                match crt(&[so_far, ghost]) {
+                    Ok(both) => next.push(both),
+                    Err(MathError::NoSolution) => {}
+                    Err(_) => return Err(overflow()),
+                }
+            }
+        }
+        next.sort();
+        next.dedup();
+        if next.len() > MAX_LINEUPS {
+            return Err(SolveError::no_answer(
+                "the ghosts line up too many ways to try",
+            ));
+        }
+        merged = next;
+    }
+    let steps = merged
+        .into_iter()
+        .map(|(steps, period)| {
+            if steps < latest {
+                steps + (latest - steps + period - 1) / period * period
+            } else {
+                steps
+            }
+        })
+        .min()
+        .ok_or_else(|| SolveError::no_answer("the ghosts are never all on Z nodes at once"))?;
+    steps.try_into().map_err(|_| overflow())
+}
+
+/// Day 8: Steps through a network following left and right turns.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const PARTS: u8 = 2;
+
+    type Input = Network;
+    type Answer = Result<usize, SolveError>;
+    type Params = NoParams;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        Network::new(lines)
+    }
+
+    fn part1(network: &Self::Input) -> Self::Answer {
+        compute_steps(network)
+    }
+
+    fn part2(network: &Self::Input) -> Self::Answer {
+        compute_ghost_steps(network)
+    }
+
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new()
+            .line("[LR]+")
+            .blank()
+            .lines(r"\w{3} = \(\w{3}, \w{3}\)")
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(random_network(rng, scale))
+    }
+}
@@ -0,0 +1,131 @@
+use aoc08::{compute_ghost_steps, compute_steps, Day08};
+use aoc_common::{Solution, SolveError};
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day08>();
+}
+
+#[test]
+fn test_ghost_offsets() {
+    // The ghosts reach Z after 1 and 2 steps, then every 2 and 3 steps.
+    let text = "L
+
+11A = (11Z, 11A)
+11Z = (11B, 11Z)
+11B = (11Z, 11B)
+22A = (22B, 22A)
+22B = (22Z, 22B)
+22Z = (22C, 22Z)
+22C = (22D, 22C)
+22D = (22Z, 22D)
+";
+    let network = Day08::parse_str(text).unwrap();
+    assert_eq!(compute_ghost_steps(&network), Ok(5));
+}
+
+#[test]
+fn test_bad_node() {
+    let err = Day08::parse_str("LR\n\nAAA = (BBB CCC)\n").err().unwrap();
+    assert_eq!((err.line, err.column, err.token.as_str()), (3, 8, "BBB"));
+    assert_eq!(err.message, "expected \",\"");
+}
+
+#[test]
+fn test_ghost_schedules() {
+    // The first ghost's loop passes two Z nodes, at 1 and 3 steps and then 5
+    // steps later each time. The second is on a Z node every 4 steps.
+    let text = "L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (1CZ, 1CZ)
+1CZ = (11D, 11D)
+11D = (11E, 11E)
+11E = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22C, 22C)
+22C = (22D, 22D)
+22D = (22Z, 22Z)
+22Z = (22B, 22B)
+";
+    let network = Day08::parse_str(text).unwrap();
+    assert_eq!(compute_ghost_steps(&network), Ok(8));
+
+    // Here the first ghost only passes a Z node once, before its loop.
+    let text = "L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11C, 11C)
+11C = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22A, 22A)
+";
+    let network = Day08::parse_str(text).unwrap();
+    assert_eq!(compute_ghost_steps(&network), Ok(1));
+}
+
+#[test]
+fn test_no_answer() {
+    let network = Day08::parse_str("LLR\n").unwrap();
+    let no_answer = |reason: &str| Err(SolveError::no_answer(reason));
+    assert_eq!(compute_steps(&network), no_answer("there's no AAA node"));
+    assert_eq!(
+        compute_ghost_steps(&network),
+        no_answer("there are no nodes ending in A")
+    );
+
+    let network = Day08::parse_str("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+    assert_eq!(
+        compute_steps(&network),
+        no_answer("ZZZ is never reached from AAA")
+    );
+    assert_eq!(
+        compute_ghost_steps(&network),
+        no_answer("the ghost from AAA never reaches a Z node")
+    );
+
+    // One ghost is on Z at even steps and the other at odd ones.
+    let text = "L
+
+11A = (11Z, 11Z)
+11Z = (11A, 11A)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22B, 22B)
+";
+    let network = Day08::parse_str(text).unwrap();
+    let err = compute_ghost_steps(&network).unwrap_err();
+    assert_eq!(
+        err,
+        SolveError::no_answer("the ghosts are never all on Z nodes at once")
+    );
+    assert_eq!(
+        aoc_common::solver::<Day08>()
+            .solve_str(text, &[2])
+            .unwrap_err()
+            .to_string(),
+        "error: no answer to part 2: the ghosts are never all on Z nodes at once"
+    );
+}
+
+#[test]
+fn test_many_offsets() {
+    // Twelve ghosts each pass four Z nodes per loop, which would be 4^12
+    // choices of offsets if they weren't merged a ghost at a time.
+    let mut text = String::from("L\n\n");
+    for ghost in 'B'..='M' {
+        let node = |i: usize| {
+            let end = if [1, 2, 4, 5].contains(&i) { 'Z' } else { 'X' };
+            format!("{}{}{}", ghost, i, end)
+        };
+        text += &format!("{}0A = ({}, {})\n", ghost, node(1), node(1));
+        for i in 1..=8 {
+            let next = node(i % 8 + 1);
+            text += &format!("{} = ({}, {})\n", node(i), next, next);
+        }
+    }
+    let network = Day08::parse_str(&text).unwrap();
+    assert_eq!(compute_ghost_steps(&network), Ok(1));
+}
@@ -0,0 +1,171 @@
+use aoc_common::{numbered, Fingerprint, Line, NoParams, ParseError, Rng, Solution, SolveError};
+use tracing::trace;
+
+/// Which end of a history to extrapolate.
+#[derive(Clone, Copy, Debug)]
+pub enum Direction {
+    Backward,
+    Forward,
+}
+
+// The differences overflow long before the values do for anything but a low
+// degree polynomial, hence all the None.
+fn differences(values: &[isize]) -> Option<Vec<isize>> {
+    values.windows(2).map(|x| x[1].checked_sub(x[0])).collect()
+}
+
+fn get_differences(values: &[isize]) -> Option<Vec<isize>> {
+    let mut diff = differences(values)?;
+
+    if diff.iter().all(|&x| x == 0) {
+        diff.push(0);
+    } else {
+        let next = diff
+            .last()
+            .unwrap()
+            .checked_add(*get_differences(&diff)?.last().unwrap())?;
+        diff.push(next);
+    }
+    trace!(target: "day09::extrapolate", "diff: {:?}", diff);
+    Some(diff)
+}
+
+fn get_prev_differences(values: &[isize]) -> Option<Vec<isize>> {
+    let mut diff = differences(values)?;
+
+    if diff.iter().all(|&x| x == 0) {
+        diff.insert(0, 0);
+    } else {
+        let prev = diff
+            .first()
+            .unwrap()
+            .checked_sub(get_prev_differences(&diff)?[0])?;
+        diff.insert(0, prev);
+    }
+    trace!(target: "day09::extrapolate", "diff: {:?}", diff);
+    Some(diff)
+}
+
+fn read_line(line: Line) -> Result<Vec<isize>, ParseError> {
+    let mut vals = Vec::new();
+    for tok in line.text.split_whitespace() {
+        vals.push(line.parse::<isize>(tok)?);
+    }
+    if vals.is_empty() {
+        return Err(line.error(line.text, "expected a history of numbers"));
+    }
+    Ok(vals)
+}
+
+/// The next value past one end of a history, from its table of differences,
+/// or `None` if somewhere in the table overflows `isize`.
+pub fn extrapolate_line(vals: &[isize], dir: Direction) -> Option<isize> {
+    let diff = match dir {
+        Direction::Forward => vals.last()?.checked_add(*get_differences(vals)?.last()?)?,
+        Direction::Backward => vals.first()?.checked_sub(get_prev_differences(vals)?[0])?,
+    };
+    trace!(target: "day09::extrapolate", "line diff: {}", diff);
+    Some(diff)
+}
+
+/// Sums [`extrapolate_line`] over every history.
+pub fn extrapolate(histories: &[Vec<isize>], dir: Direction) -> Result<isize, SolveError> {
+    let mut sum: isize = 0;
+
+    for (i, vals) in histories.iter().enumerate() {
+        sum = extrapolate_line(vals, dir)
+            .and_then(|next| sum.checked_add(next))
+            .ok_or_else(|| {
+                SolveError::no_answer(format!("the history on line {} overflows isize", i + 1))
+            })?;
+    }
+
+    Ok(sum)
+}
+
+// Polynomial sequences, built up from a constant row of differences by adding
+// a random first value at each level.
+fn random_histories(rng: &mut Rng, lines: usize, len: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..lines {
+        let degree = rng.index(len - 1);
+        let mut vals = vec![rng.range(-5..6) as isize; len - degree];
+        for _ in 0..degree {
+            let mut next = vec![rng.range(-10..11) as isize];
+            for diff in &vals {
+                next.push(next[next.len() - 1] + diff);
+            }
+            vals = next;
+        }
+        let toks: Vec<String> = vals.iter().map(|v| v.to_string()).collect();
+        text += &toks.join(" ");
+        text.push('\n');
+    }
+    text
+}
+
+/// Day 9: Histories extrapolated from their differences.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const PARTS: u8 = 2;
+
+    type Input = Vec<Vec<isize>>;
+    type Answer = Result<isize, SolveError>;
+    type Params = NoParams;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        numbered(lines).map(read_line).collect()
+    }
+
+    fn part1(histories: &Self::Input) -> Self::Answer {
+        extrapolate(histories, Direction::Forward)
+    }
+
+    fn part2(histories: &Self::Input) -> Self::Answer {
+        extrapolate(histories, Direction::Backward)
+    }
+
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new().lines(r"-?\d+( -?\d+)*")
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(random_histories(rng, 200 * scale, 21))
+    }
+}
+
+// The values one step either side of the polynomial through all the points,
+// straight from Newton's forward difference formula instead of the tables.
+#[cfg(test)]
+fn brute_force(vals: &[isize]) -> (i128, i128) {
+    let n = vals.len();
+    let mut binomial = vec![1i128; n + 1];
+    for k in 1..=n {
+        binomial[k] = binomial[k - 1] * (n + 1 - k) as i128 / k as i128;
+    }
+    let sign = |k: usize| if k.is_multiple_of(2) { 1 } else { -1 };
+    let mut next = 0;
+    let mut prev = 0;
+    for (k, &v) in vals.iter().enumerate() {
+        next += sign(n - 1 - k) * binomial[k] * v as i128;
+        prev += sign(k) * binomial[k + 1] * v as i128;
+    }
+    (next, prev)
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn extrapolation_matches_brute_force(vals in prop::collection::vec(-50isize..50, 1..12)) {
+        let (next, prev) = brute_force(&vals);
+        prop_assert_eq!(extrapolate_line(&vals, Direction::Forward).map(|v| v as i128), Some(next));
+        prop_assert_eq!(extrapolate_line(&vals, Direction::Backward).map(|v| v as i128), Some(prev));
+    }
+}
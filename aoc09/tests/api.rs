@@ -0,0 +1,23 @@
+use aoc09::Day09;
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day09>();
+}
+
+#[test]
+fn test_solve_str() {
+    let day = aoc_common::solver::<Day09>();
+    assert_eq!(day.solve_str("0 3 6 9 12 15\n", &[1]).unwrap(), ["18"]);
+}
+
+#[test]
+fn test_overflow() {
+    let day = aoc_common::solver::<Day09>();
+    let text = "0 1 2\n9223372036854775807 -9223372036854775808 0\n";
+    let err = day.solve_str(text, &[1]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "error: no answer to part 1: the history on line 2 overflows isize"
+    );
+}
@@ -0,0 +1,450 @@
+use aoc_common::graph::{reachable, Graph};
+use aoc_common::{
+    metrics, numbered, Fingerprint, Grid, NoParams, ParseError, Pos, Rng, Solution, SolveError,
+};
+use std::collections::HashSet;
+use tracing::{debug, enabled, trace, Level};
+
+/// A field of pipes, with the tile under S already replaced by the pipe it
+/// must be, and a loop through S that's been checked to close.
+#[derive(Debug)]
+pub struct PipeMap {
+    height: usize,
+    width: usize,
+    start: Pos,
+    pipemap: Grid<char>,
+}
+
+impl std::fmt::Display for PipeMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "pipemap[{}][{}]", self.height, self.width)?;
+        writeln!(f, "start: {:?}", self.start)?;
+        write!(f, "{}", self.pipemap)
+    }
+}
+
+impl PipeMap {
+    fn new(lines: &[String]) -> Result<Self, ParseError> {
+        let mut pipemap = Grid::parse(lines, |c| "|-LJ7F.S".contains(c).then_some(c))?;
+        let (height, width) = (pipemap.height(), pipemap.width());
+
+        let tile_error = |(x, y): Pos, message| {
+            let line = numbered(lines).nth(y).unwrap();
+            let (i, c) = line.text.char_indices().nth(x).unwrap();
+            line.error(&line.text[i..i + c.len_utf8()], message)
+        };
+        let starts: Vec<Pos> = pipemap
+            .iter()
+            .filter(|&(_, &c)| c == 'S')
+            .map(|(pos, _)| pos)
+            .collect();
+        let start = match starts[..] {
+            [] => return Err(ParseError::missing(height - 1, "no start tile 'S'")),
+            [start] => start,
+            [_, second, ..] => return Err(tile_error(second, "second start tile")),
+        };
+
+        // Replace S with the correctly shaped piece to make things easier.
+        let (x, y) = start;
+        let mut valid = (false, false, false, false);
+        // Check up
+        if y > 0 {
+            let up = pipemap[(x, y - 1)];
+            match up {
+                'F' | '7' | '|' => {
+                    valid.0 = true;
+                }
+                _ => {}
+            }
+        }
+        if y + 1 < height {
+            let down = pipemap[(x, y + 1)];
+            match down {
+                'L' | 'J' | '|' => {
+                    valid.1 = true;
+                }
+                _ => {}
+            }
+        }
+        if x > 0 {
+            let left = pipemap[(x - 1, y)];
+            match left {
+                'F' | 'L' | '-' => {
+                    valid.2 = true;
+                }
+                _ => {}
+            }
+        }
+        if x + 1 < width {
+            let right = pipemap[(x + 1, y)];
+            match right {
+                'J' | '7' | '-' => {
+                    valid.3 = true;
+                }
+                _ => {}
+            }
+        }
+
+        let replace_start = match valid {
+            // (Up, Down, Left, Right)
+            (true, true, false, false) => '|',
+            (true, false, true, false) => 'J',
+            (true, false, false, true) => 'L',
+            (false, true, true, false) => '7',
+            (false, true, false, true) => 'F',
+            (false, false, true, true) => '-',
+            (_, _, _, _) => return Err(tile_error(start, "start doesn't join exactly two pipes")),
+        };
+
+        pipemap[start] = replace_start;
+
+        let map = PipeMap {
+            height,
+            width,
+            start,
+            pipemap,
+        };
+
+        // Follow the pipe from S all the way round, so nothing after this
+        // has to cope with a loop that breaks off.
+        let (mut prev, mut at) = (start, start);
+        loop {
+            let ends = map.connections(at);
+            if ends.len() < 2 {
+                return Err(tile_error(at, "this pipe leads off the map"));
+            }
+            let next = if ends[0] != prev { ends[0] } else { ends[1] };
+            if !map.connections(next).contains(&at) {
+                return Err(tile_error(next, "the loop from S breaks here"));
+            }
+            (prev, at) = (at, next);
+            if at == start {
+                break;
+            }
+        }
+
+        Ok(map)
+    }
+
+    // The tiles that the pipe at pos joins up with. Parsing has already
+    // replaced S with its pipe.
+    fn connections(&self, (x, y): Pos) -> Vec<Pos> {
+        let (up, down, left, right) = (
+            y.checked_sub(1).map(|y| (x, y)),
+            Some((x, y + 1)),
+            x.checked_sub(1).map(|x| (x, y)),
+            Some((x + 1, y)),
+        );
+        let ends = match self.pipemap[(x, y)] {
+            '|' => [up, down],
+            '-' => [left, right],
+            'L' => [up, right],
+            'J' => [up, left],
+            '7' => [down, left],
+            'F' => [down, right],
+            _ => [None, None],
+        };
+        ends.into_iter()
+            .flatten()
+            .filter(|&pos| self.pipemap.contains(pos))
+            .collect()
+    }
+
+    /// Everything joined up to the start, which is the loop since every pipe
+    /// on it joins exactly its two neighbours on the loop.
+    pub fn get_loop(&self) -> HashSet<Pos> {
+        debug!(target: "day10::grid", "{}", self);
+        let set = reachable(self, self.start);
+        trace!(target: "day10::loop", "Loop from {:?} has {} tiles", self.start, set.len());
+        set
+    }
+
+    /// Steps along the loop to the tile farthest from the start.
+    pub fn get_max_distance(&self) -> usize {
+        let loop_len = self.get_loop().len();
+        metrics::record("loop_len", loop_len as i64);
+
+        // Max distance is halfway around the loop (rounding up)
+        loop_len.div_ceil(2)
+    }
+
+    /// Tiles inside the loop that aren't part of it.
+    pub fn get_enclosed_area(&self) -> usize {
+        let mut area = 0;
+        let set = self.get_loop();
+
+        // Use parity to determine inside/outside. Odd number of border
+        // crossings will be inside, and even numbers will be outside.A
+        // We only need to track |, L, and J (and maybe S) for border
+        // crossing points. F and 7
+        let render = enabled!(target: "day10::area", Level::TRACE);
+        for y in 0..self.height {
+            // Always start outside
+            let mut inside = false;
+            let mut row = String::new();
+            for x in 0..self.width {
+                let c = (x, y);
+                if set.contains(&c) {
+                    let shape = self.pipemap[c];
+                    if render {
+                        row.push(shape);
+                    }
+                    match shape {
+                        '|' | 'J' | 'L' => {
+                            inside = !inside;
+                        }
+                        _ => {
+                            // 7, F and - don't cross.
+                        }
+                    }
+                } else {
+                    if inside {
+                        area += 1;
+                    }
+                    if render {
+                        row.push(if inside { 'I' } else { 'O' });
+                    }
+                }
+            }
+            if render {
+                trace!(target: "day10::area", "{}", row);
+            }
+        }
+
+        area
+    }
+}
+
+// The loop is the outline of a random tree. The tree grows over part of a
+// cells x cells grid, and each of its cells is a 2x2 block of squares, with a
+// strip of squares bridging the cells it joins. That shape has no holes or
+// corners that only touch diagonally, so its outline is one simple loop
+// through the square corners, which are the tiles. Everything else is junk.
+fn random_pipes(rng: &mut Rng, cells: usize) -> String {
+    let at = |x: usize, y: usize| y * cells + x;
+    let mut in_tree = vec![false; cells * cells];
+    let mut right = vec![false; cells * cells];
+    let mut down = vec![false; cells * cells];
+
+    let target = cells * cells * (5 + rng.index(5)) / 10;
+    let mut grown = 1;
+    let first = (rng.index(cells), rng.index(cells));
+    in_tree[at(first.0, first.1)] = true;
+    let mut frontier = vec![first];
+    let mut edges: Vec<(Pos, Pos)> = Vec::new();
+    while grown < target {
+        if let Some((x, y)) = frontier.pop() {
+            let mut next = vec![(x + 1, y), (x, y + 1)];
+            next.extend(x.checked_sub(1).map(|x| (x, y)));
+            next.extend(y.checked_sub(1).map(|y| (x, y)));
+            edges.extend(
+                next.into_iter()
+                    .filter(|&(x, y)| x < cells && y < cells)
+                    .map(|to| ((x, y), to)),
+            );
+        }
+        if edges.is_empty() {
+            break;
+        }
+        let (from, to) = edges.swap_remove(rng.index(edges.len()));
+        if in_tree[at(to.0, to.1)] {
+            continue;
+        }
+        in_tree[at(to.0, to.1)] = true;
+        let (a, b) = (from.min(to), from.max(to));
+        if a.1 == b.1 {
+            right[at(a.0, a.1)] = true;
+        } else {
+            down[at(a.0, a.1)] = true;
+        }
+        frontier.push(to);
+        grown += 1;
+    }
+
+    let filled = |sx: i64, sy: i64| {
+        if sx < 0 || sy < 0 {
+            return false;
+        }
+        let (x, y) = (sx as usize / 3, sy as usize / 3);
+        if x >= cells || y >= cells {
+            return false;
+        }
+        match (sx % 3, sy % 3) {
+            (2, 2) => false,
+            (2, _) => right[at(x, y)],
+            (_, 2) => down[at(x, y)],
+            _ => in_tree[at(x, y)],
+        }
+    };
+
+    // Tile (x, y) is the corner at (x - 1, y - 1), leaving a border of junk.
+    let size = 3 * cells + 2;
+    let mut tiles = vec![vec!['.'; size]; size];
+    let mut on_loop = Vec::new();
+    for (y, row) in tiles.iter_mut().enumerate() {
+        for (x, tile) in row.iter_mut().enumerate() {
+            let (px, py) = (x as i64 - 1, y as i64 - 1);
+            let (nw, ne) = (filled(px - 1, py - 1), filled(px, py - 1));
+            let (sw, se) = (filled(px - 1, py), filled(px, py));
+            let arms = (nw != ne, sw != se, nw != sw, ne != se);
+            *tile = match arms {
+                // (Up, Down, Left, Right)
+                (true, true, false, false) => '|',
+                (false, false, true, true) => '-',
+                (true, false, false, true) => 'L',
+                (true, false, true, false) => 'J',
+                (false, true, true, false) => '7',
+                (false, true, false, true) => 'F',
+                _ => *rng.choose(&['|', '-', 'L', 'J', '7', 'F', '.', '.', '.']),
+            };
+            if arms != (false, false, false, false) {
+                on_loop.push((x, y));
+            }
+        }
+    }
+
+    // Junk next to the start mustn't look like it joins it.
+    let (sx, sy) = *rng.choose(&on_loop);
+    tiles[sy][sx] = 'S';
+    let beside = [
+        (sx, sy - 1, "F7|"),
+        (sx, sy + 1, "LJ|"),
+        (sx - 1, sy, "FL-"),
+        (sx + 1, sy, "J7-"),
+    ];
+    for (x, y, joins) in beside {
+        if !on_loop.contains(&(x, y)) && joins.contains(tiles[y][x]) {
+            tiles[y][x] = '.';
+        }
+    }
+
+    let rows: Vec<String> = tiles.iter().map(|row| row.iter().collect()).collect();
+    rows.join("\n") + "\n"
+}
+
+impl Graph for PipeMap {
+    type Node = Pos;
+
+    fn neighbours(&self, pos: Pos) -> impl Iterator<Item = (Pos, u64)> {
+        self.connections(pos).into_iter().map(|next| (next, 1))
+    }
+}
+
+/// Day 10: The loop of pipe through S and the tiles it encloses.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const PARTS: u8 = 2;
+
+    type Input = PipeMap;
+    type Answer = Result<usize, SolveError>;
+    type Params = NoParams;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        PipeMap::new(lines)
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Answer {
+        Ok(grid.get_max_distance())
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Answer {
+        Ok(grid.get_enclosed_area())
+    }
+
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new().grid("[-|LJ7F.S]").containing("S")
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let cells = 46.0 * (scale as f64).sqrt();
+        Some(random_pipes(rng, cells as usize))
+    }
+}
+
+// Search out from the start for the farthest tile, joining tiles whose pipes
+// face each other, and flood the outside of a picture of the loop drawn at
+// three times the size, where the gaps between pipes are real gaps.
+#[cfg(test)]
+fn brute_force(map: &PipeMap) -> (usize, usize) {
+    let opens = |c: char, step: (i64, i64)| match step {
+        (0, -1) => "|LJ".contains(c),
+        (0, 1) => "|7F".contains(c),
+        (-1, 0) => "-J7".contains(c),
+        _ => "-LF".contains(c),
+    };
+    let joins = |(x, y): Pos| -> Vec<(i64, i64)> {
+        [(0, -1), (0, 1), (-1, 0), (1, 0)]
+            .into_iter()
+            .filter(|&(dx, dy)| {
+                let next = (x as i64 + dx, y as i64 + dy);
+                let tile = usize::try_from(next.0)
+                    .ok()
+                    .zip(usize::try_from(next.1).ok())
+                    .and_then(|pos| map.pipemap.get(pos));
+                opens(map.pipemap[(x, y)], (dx, dy)) && tile.is_some_and(|&t| opens(t, (-dx, -dy)))
+            })
+            .collect()
+    };
+
+    let mut distance = std::collections::HashMap::from([(map.start, 0)]);
+    let mut queue = std::collections::VecDeque::from([map.start]);
+    while let Some((x, y)) = queue.pop_front() {
+        for (dx, dy) in joins((x, y)) {
+            let next = ((x as i64 + dx) as usize, (y as i64 + dy) as usize);
+            if !distance.contains_key(&next) {
+                distance.insert(next, distance[&(x, y)] + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    let farthest = *distance.values().max().unwrap();
+    let on_loop: HashSet<Pos> = distance.into_keys().collect();
+
+    let (width, height) = (3 * map.width, 3 * map.height);
+    let mut wall = Grid::filled(width, height, false);
+    for &(x, y) in &on_loop {
+        let (cx, cy) = (3 * x as i64 + 1, 3 * y as i64 + 1);
+        wall[(cx as usize, cy as usize)] = true;
+        for (dx, dy) in joins((x, y)) {
+            wall[((cx + dx) as usize, (cy + dy) as usize)] = true;
+        }
+    }
+    let mut outside = Grid::filled(width, height, false);
+    let mut todo: Vec<Pos> = wall
+        .positions()
+        .filter(|&(x, y)| x == 0 || y == 0 || x == width - 1 || y == height - 1)
+        .collect();
+    while let Some(pos) = todo.pop() {
+        if wall[pos] || outside[pos] {
+            continue;
+        }
+        outside[pos] = true;
+        todo.extend(wall.neighbours4(pos));
+    }
+    let enclosed = map
+        .pipemap
+        .positions()
+        .filter(|&(x, y)| !on_loop.contains(&(x, y)) && !outside[(3 * x + 1, 3 * y + 1)])
+        .count();
+    (farthest, enclosed)
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn loop_matches_brute_force(seed in any::<u64>(), cells in 1usize..6) {
+        let text = random_pipes(&mut Rng::new(seed), cells);
+        let map = Day10::parse_str(&text).unwrap();
+        prop_assert_eq!(
+            (map.get_max_distance(), map.get_enclosed_area()),
+            brute_force(&map)
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use aoc10::Day10;
+use aoc_common::Solution;
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day10>();
+}
+
+#[test]
+fn test_unknown_tile() {
+    let lines = vec![
+        ".....".to_string(),
+        ".S-7.".to_string(),
+        ".|x|.".to_string(),
+    ];
+    let err = Day10::parse(&lines).unwrap_err();
+    assert_eq!((err.line, err.column, err.token.as_str()), (3, 3, "x"));
+}
+
+#[test]
+fn test_broken_loop() {
+    let lines = |text: &str| aoc_common::to_lines(text);
+    let err = Day10::parse(&lines(".S-7.\n.|.|.\n.L.J.")).unwrap_err();
+    assert_eq!((err.line, err.column, err.token.as_str()), (3, 3, "."));
+    assert!(err.to_string().contains("the loop from S breaks here"));
+    let err = Day10::parse(&lines("S-7\n|.|\nL--")).unwrap_err();
+    assert_eq!((err.line, err.column), (3, 3));
+    assert!(Day10::parse(&lines(".S-7.\n.|.|.\n.L-J.")).is_ok());
+}
@@ -0,0 +1,162 @@
+use aoc_common::params::Validate;
+use aoc_common::{metrics, Fingerprint, Grid, ParseError, Pos, Rng, Solution, SolveError};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+/// How many rows or columns each empty one counts as, in each part.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub expansion: usize,
+    pub older_expansion: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            expansion: 2,
+            older_expansion: 1_000_000,
+        }
+    }
+}
+
+// An empty row can't shrink away to nothing.
+impl Validate for Params {
+    fn validate(&self) -> Result<(), String> {
+        if self.expansion == 0 || self.older_expansion == 0 {
+            return Err("expansion and older_expansion must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// The galaxies in an image and the rows and columns with none in them.
+#[derive(Debug)]
+pub struct Universe {
+    galaxies: Vec<Pos>,
+    // How many empty columns come before each x, and empty rows before each
+    // y, so the gaps between two galaxies are one subtraction.
+    x_gaps: Vec<usize>,
+    y_gaps: Vec<usize>,
+}
+
+fn gaps_before(empty: impl Iterator<Item = bool>) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(empty.scan(0, |count, empty| {
+            *count += usize::from(empty);
+            Some(*count)
+        }))
+        .collect()
+}
+
+impl Universe {
+    fn new(lines: &[String]) -> Result<Self, ParseError> {
+        let image = Grid::parse(lines, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        let galaxies = image
+            .iter()
+            .filter(|&(_, &g)| g)
+            .map(|(pos, _)| pos)
+            .collect();
+
+        let x_gaps = gaps_before((0..image.width()).map(|x| !image.column(x).any(|&g| g)));
+        let y_gaps = gaps_before((0..image.height()).map(|y| !image.row(y).contains(&true)));
+
+        Ok(Self {
+            galaxies,
+            x_gaps,
+            y_gaps,
+        })
+    }
+
+    // Find shortest path between two coordinates
+    // In the case of these up/down/left/right problems, this is just equal
+    // to the x difference plus the y difference. For this problem, we also
+    // have an expansion penalty for empty rows/cols in the Universe.
+    // None if it overflows, which a big enough expansion can make it do.
+    fn distance(&self, c1: &Pos, c2: &Pos, expansion: usize) -> Option<usize> {
+        if c1 == c2 {
+            return Some(0);
+        }
+        let low_x = std::cmp::min(c1.0, c2.0);
+        let high_x = std::cmp::max(c1.0, c2.0);
+        let low_y = std::cmp::min(c1.1, c2.1);
+        let high_y = std::cmp::max(c1.1, c2.1);
+        let gaps =
+            self.x_gaps[high_x] - self.x_gaps[low_x] + self.y_gaps[high_y] - self.y_gaps[low_y];
+        gaps.checked_mul(expansion - 1)?
+            .checked_add(high_x - low_x + high_y - low_y)
+    }
+}
+
+// Sparse galaxies, with some rows and columns left empty to expand.
+fn random_image(rng: &mut Rng, size: usize) -> String {
+    let empty_cols: Vec<bool> = (0..size).map(|_| rng.chance(0.08)).collect();
+    let mut text = String::new();
+    for _ in 0..size {
+        let empty_row = rng.chance(0.08);
+        for &empty_col in &empty_cols {
+            let galaxy = !empty_row && !empty_col && rng.chance(0.025);
+            text.push(if galaxy { '#' } else { '.' });
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Sums the distances between every pair of galaxies, with each empty row and
+/// column counting as `expansion` of them.
+pub fn compute_shortest_paths(universe: &Universe, expansion: usize) -> Result<usize, SolveError> {
+    let mut dist: usize = 0;
+
+    debug!(target: "day11::universe", "universe: {:?}", universe);
+
+    let galaxies = universe.galaxies.len();
+    metrics::record("galaxies", galaxies as i64);
+    for i in 0..galaxies {
+        for j in i..galaxies {
+            dist = universe
+                .distance(&universe.galaxies[i], &universe.galaxies[j], expansion)
+                .and_then(|d| dist.checked_add(d))
+                .ok_or_else(|| SolveError::no_answer("the distances overflow usize"))?;
+        }
+    }
+
+    Ok(dist)
+}
+
+/// Day 11: Distances between galaxies in an expanding image.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const PARTS: u8 = 2;
+
+    type Input = Universe;
+    type Answer = Result<usize, SolveError>;
+    type Params = Params;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        Universe::new(lines)
+    }
+
+    fn part1(universe: &Self::Input) -> Self::Answer {
+        compute_shortest_paths(universe, Self::params().expansion)
+    }
+
+    fn part2(universe: &Self::Input) -> Self::Answer {
+        compute_shortest_paths(universe, Self::params().older_expansion)
+    }
+
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new().grid("[.#]")
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        let size = 140.0 * (scale as f64).sqrt();
+        Some(random_image(rng, size as usize))
+    }
+}
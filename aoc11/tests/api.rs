@@ -0,0 +1,26 @@
+use aoc11::{compute_shortest_paths, Day11};
+use aoc_common::{input_path, read_lines, Solution};
+
+#[test]
+fn test_prelim2() {
+    let lines = read_lines(input_path!("prelim.txt")).unwrap();
+    let universe = Day11::parse(&lines).unwrap();
+    let steps = compute_shortest_paths(&universe, 10);
+    assert_eq!(steps, Ok(1030));
+    let steps = compute_shortest_paths(&universe, 100);
+    assert_eq!(steps, Ok(8410));
+    assert!(compute_shortest_paths(&universe, usize::MAX).is_err());
+}
+
+#[test]
+fn test_bad_params() {
+    let day = aoc_common::solver::<Day11>();
+    let values = aoc_common::params::Table::from_iter([("expansion".to_string(), 0.into())]);
+    let err = day.check_params(&values).unwrap_err();
+    assert_eq!(err, "expansion and older_expansion must be at least 1");
+}
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day11>();
+}
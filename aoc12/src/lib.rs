@@ -0,0 +1,319 @@
+use aoc_common::params::Validate;
+use aoc_common::{numbered, Fingerprint, Line, ParseError, Rng, Solution, SolveError};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, trace};
+
+// What the row so far has to do next, for each (group, run length) it could
+// have got to. These are laid out in order, so a spring either leaves the row
+// where it is or moves it on to the next one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Step {
+    // Between groups: any number of working springs, then the next group.
+    Gap,
+    // Part way into a group, which needs another damaged spring.
+    Run,
+    // A group's just finished, which needs a working spring after it.
+    End,
+    // Every group's finished, so only working springs can follow.
+    Done,
+}
+
+fn steps(groups: &[usize]) -> Vec<Step> {
+    let mut steps = Vec::new();
+    for &size in groups {
+        steps.push(Step::Gap);
+        steps.extend(std::iter::repeat_n(Step::Run, size - 1));
+        steps.push(Step::End);
+    }
+    steps.push(Step::Done);
+    steps
+}
+
+// Works along the springs one at a time, counting the ways to get to each
+// step. A count goes to None once it overflows, and stays None for every step
+// it leads to, since those can only be got to more ways.
+fn count_matches(springs: &[u8], groups: &[usize]) -> Option<usize> {
+    let steps = steps(groups);
+    let add = |count: &mut Option<usize>, ways: Option<usize>| {
+        *count = count.zip(ways).and_then(|(a, b)| a.checked_add(b));
+    };
+    let mut row = vec![Some(0); steps.len()];
+    let mut next = row.clone();
+    row[0] = Some(1);
+    // Only the steps in lo..hi can have been got to.
+    let (mut lo, mut hi) = (0, 1);
+    for &spring in springs {
+        let (working, broken) = match spring {
+            b'.' => (true, false),
+            b'#' => (false, true),
+            b'?' => (true, true),
+            // read_record only lets through these three.
+            _ => unreachable!("unknown spring {:?}", spring as char),
+        };
+        let end = (hi + 1).min(steps.len());
+        next[lo..end].fill(Some(0));
+        for i in lo..hi {
+            let ways = row[i];
+            if ways == Some(0) {
+                continue;
+            }
+            match steps[i] {
+                Step::Gap | Step::Done if working => add(&mut next[i], ways),
+                _ => {}
+            }
+            match steps[i] {
+                Step::Gap | Step::Run if broken => add(&mut next[i + 1], ways),
+                Step::End if working => add(&mut next[i + 1], ways),
+                _ => {}
+            }
+        }
+        std::mem::swap(&mut row, &mut next);
+        hi = end;
+        while lo < hi && row[lo] == Some(0) {
+            lo += 1;
+        }
+        if lo == hi {
+            return Some(0);
+        }
+        trace!(target: "day12::matches", "{}: {:?}", spring as char, &row[lo..hi]);
+    }
+    // The last group can run right up to the end of the row. Anything below lo
+    // is left over from earlier springs.
+    let last = |i: usize| if i >= lo { row[i] } else { Some(0) };
+    let mut total = last(steps.len() - 1);
+    if steps.len() > 1 {
+        add(&mut total, last(steps.len() - 2));
+    }
+    total
+}
+
+/// How many copies of each record part 2 unfolds it to.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub copies: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { copies: 5 }
+    }
+}
+
+impl Validate for Params {
+    fn validate(&self) -> Result<(), String> {
+        if self.copies == 0 {
+            return Err("copies must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// One row of springs, with `?` where the condition is unknown, and the sizes
+/// of its groups of damaged springs.
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub springs: String,
+    pub groups: Vec<usize>,
+}
+
+fn read_record(line: Line) -> Result<Record, ParseError> {
+    let toks: Vec<_> = line.text.split_whitespace().collect();
+    if toks.len() != 2 {
+        return Err(line.error(line.text, "expected springs and group sizes"));
+    }
+    if let Some((i, c)) = toks[0].char_indices().find(|&(_, c)| !".#?".contains(c)) {
+        let token = &toks[0][i..i + c.len_utf8()];
+        return Err(line.error(token, format!("unknown spring {:?}", c)));
+    }
+    let mut groups = Vec::new();
+    for g in toks[1].split(",") {
+        match line.parse::<usize>(g)? {
+            0 => return Err(line.error(g, "group sizes must be positive")),
+            size => groups.push(size),
+        }
+    }
+    Ok(Record {
+        springs: toks[0].to_string(),
+        groups,
+    })
+}
+
+// Lay out a real row of springs and then hide some of them, so that every
+// record has at least one arrangement.
+fn random_records(rng: &mut Rng, records: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..records {
+        let len = rng.range(4..21) as usize;
+        let mut springs = Vec::new();
+        let mut groups = Vec::new();
+        while springs.len() < len {
+            if rng.chance(0.5) {
+                springs.push('.');
+                continue;
+            }
+            let size = (rng.index(5) + 1).min(len - springs.len());
+            springs.extend(std::iter::repeat_n('#', size));
+            groups.push(size.to_string());
+            if springs.len() < len {
+                springs.push('.');
+            }
+        }
+        if groups.is_empty() {
+            springs[0] = '#';
+            groups.push("1".to_string());
+        }
+        let hidden = rng.range(3..8) as f64 / 10.0;
+        let springs: String = springs
+            .into_iter()
+            .map(|c| if rng.chance(hidden) { '?' } else { c })
+            .collect();
+        text += &format!("{} {}\n", springs, groups.join(","));
+    }
+    text
+}
+
+/// How many ways the unknown springs can be filled in to fit the groups,
+/// once the record is unfolded to `copies` copies of itself joined by `?`.
+/// One copy is the record as written. `None` if there are more than fit in a
+/// `usize`.
+pub fn get_arrangements(record: &Record, copies: usize) -> Option<usize> {
+    let groups = record.groups.repeat(copies);
+    let springs = vec![record.springs.as_str(); copies].join("?");
+    if copies > 1 {
+        trace!(target: "day12::expand", "expanded_groups: {:?}", groups);
+        trace!(target: "day12::expand", "expanded_springs: {}", springs);
+    }
+
+    // Each group needs its springs and one to separate it from the next.
+    let needed = groups
+        .iter()
+        .fold(0, |a: usize, &g| a.saturating_add(g).saturating_add(1));
+    // read_record never makes an empty group, which nothing could fit.
+    let arr = if needed > springs.len() + 1 || groups.contains(&0) {
+        Some(0)
+    } else {
+        count_matches(springs.as_bytes(), &groups)
+    };
+    debug!(target: "day12::arrangements", "arrangements {}: {:?}", record.springs, arr);
+    arr
+}
+
+/// Sums [`get_arrangements`] over every record.
+pub fn get_total_arrangements(records: &[Record], copies: usize) -> Result<usize, SolveError> {
+    let mut total: usize = 0;
+    for (i, record) in records.iter().enumerate() {
+        total = get_arrangements(record, copies)
+            .and_then(|arr| total.checked_add(arr))
+            .ok_or_else(|| {
+                let reason = format!("the arrangements overflow usize by line {}", i + 1);
+                SolveError::no_answer(reason)
+            })?;
+    }
+    Ok(total)
+}
+
+/// Day 12: Arrangements of damaged springs that fit the records.
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const PARTS: u8 = 2;
+
+    type Input = Vec<Record>;
+    type Answer = Result<usize, SolveError>;
+    type Params = Params;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        numbered(lines).map(read_record).collect()
+    }
+
+    fn part1(records: &Self::Input) -> Self::Answer {
+        get_total_arrangements(records, 1)
+    }
+
+    fn part2(records: &Self::Input) -> Self::Answer {
+        get_total_arrangements(records, Self::params().copies)
+    }
+
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new().lines(r"[.#?]+ \d+(,\d+)*")
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(random_records(rng, 1000 * scale))
+    }
+}
+
+// Try every way of filling in the unknown springs.
+#[cfg(test)]
+fn brute_force(springs: &str, groups: &[usize]) -> usize {
+    let unknown: Vec<usize> = springs.match_indices('?').map(|(i, _)| i).collect();
+    let mut row = springs.as_bytes().to_vec();
+    (0..1u32 << unknown.len())
+        .filter(|mask| {
+            for (bit, &i) in unknown.iter().enumerate() {
+                row[i] = if mask >> bit & 1 == 1 { b'#' } else { b'.' };
+            }
+            let runs: Vec<usize> = row
+                .split(|&c| c == b'.')
+                .filter(|run| !run.is_empty())
+                .map(|run| run.len())
+                .collect();
+            runs == groups
+        })
+        .count()
+}
+
+#[cfg(test)]
+fn brute_force_unfolded(record: &Record, copies: usize) -> usize {
+    let springs = vec![record.springs.as_str(); copies].join("?");
+    brute_force(&springs, &record.groups.repeat(copies))
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+fn record(springs: &'static str, max_len: usize) -> impl Strategy<Value = Record> {
+    (
+        proptest::string::string_regex(&format!("[{}]{{1,{}}}", springs, max_len)).unwrap(),
+        prop::collection::vec(1usize..4, 1..4),
+    )
+        .prop_map(|(springs, groups)| Record { springs, groups })
+}
+
+#[cfg(test)]
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn arrangements_match_brute_force(record in record(".#?", 10)) {
+        prop_assert_eq!(
+            get_arrangements(&record, 1),
+            Some(brute_force(&record.springs, &record.groups))
+        );
+    }
+
+    // Unfolding multiplies the unknowns by up to five, so only a couple are
+    // allowed.
+    #[test]
+    fn unfolded_arrangements_match_brute_force(
+        record in record(".#", 6),
+        hidden in prop::collection::vec(0usize..6, 0..3),
+        copies in 1usize..6,
+    ) {
+        let mut record = record;
+        let mut springs = record.springs.into_bytes();
+        for i in hidden {
+            if i < springs.len() {
+                springs[i] = b'?';
+            }
+        }
+        record.springs = String::from_utf8(springs).unwrap();
+        prop_assert_eq!(
+            get_arrangements(&record, copies),
+            Some(brute_force_unfolded(&record, copies))
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use aoc12::{get_arrangements, Day12};
+use aoc_common::Solution;
+
+#[test]
+fn test_basic() {
+    let records =
+        Day12::parse(&["#.#.### 1,1,3".to_string(), "???.### 1,1,3".to_string()]).unwrap();
+    assert_eq!(get_arrangements(&records[0], 1), Some(1));
+    assert_eq!(get_arrangements(&records[1], 1), Some(1));
+}
+
+#[test]
+fn test_overflow() {
+    let line = format!("{} 1,1,1,1,1,1,1,1,1,1", "?".repeat(600));
+    let records = Day12::parse(&[line, "# 18446744073709551615".to_string()]).unwrap();
+    assert_eq!(get_arrangements(&records[0], 1), None);
+    assert_eq!(get_arrangements(&records[1], 1), Some(0));
+    assert!(Day12::part1(&records).is_err());
+}
+
+#[test]
+fn test_long_record() {
+    // Far longer than any recursion over the springs would have room for.
+    let len = 200_000;
+    let records = Day12::parse(&[
+        format!("{} {}", "#".repeat(len), len),
+        format!("?{}? {}", "#".repeat(len), len),
+    ])
+    .unwrap();
+    assert_eq!(get_arrangements(&records[0], 1), Some(1));
+    assert_eq!(Day12::part2(&records), Ok(2));
+    assert_eq!(get_arrangements(&records[1], 10), Some(1));
+}
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day12>();
+}
+
+#[test]
+fn test_bad_params() {
+    let day = aoc_common::solver::<Day12>();
+    let values = aoc_common::params::Table::from_iter([("copies".to_string(), 0.into())]);
+    assert_eq!(
+        day.check_params(&values).unwrap_err(),
+        "copies must be at least 1"
+    );
+}
@@ -0,0 +1,198 @@
+use aoc_common::{
+    numbered, Fingerprint, Grid, Line, NoParams, ParseError, Rng, Solution, SolveError,
+};
+use tracing::{debug, trace};
+
+// Draw a pattern for debugging, with the mirror line between columns or rows.
+fn render(graph: &Grid<bool>, column: Option<usize>, row: Option<usize>) -> String {
+    let mut out = String::new();
+    for (y, line) in graph.rows().enumerate() {
+        if row == Some(y) {
+            out += &"-".repeat(line.len());
+            out.push('\n');
+        }
+        for (x, &cell) in line.iter().enumerate() {
+            if column == Some(x) {
+                out.push('|');
+            }
+            out.push(if cell { '#' } else { '.' });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// The columns left of a vertical line of reflection, or 100 times the rows
+/// above a horizontal one, or `None` if the pattern has neither.
+pub fn find_reflection(graph: &Grid<bool>) -> Option<usize> {
+    // Check for vertical reflections first
+    // Check for horizontal reflections next
+    let width = graph.width();
+    let height = graph.height();
+
+    'check_outer: for midpoint in 1..width {
+        // go left and right simultaneously to check.
+        // midpoint is the actual right side
+        trace!(target: "day13::reflect", "Checking horizontal midpoint: {}", midpoint);
+        for row in graph.rows() {
+            let mut keep_going = true;
+            let mut left = midpoint - 1;
+            let mut right = midpoint;
+            while keep_going {
+                if row[left] != row[right] {
+                    trace!(target: "day13::reflect", "failed l: {} r: {}", left, right);
+                    continue 'check_outer;
+                }
+                if left == 0 {
+                    keep_going = false;
+                } else {
+                    left -= 1;
+                }
+                if right == width - 1 {
+                    keep_going = false;
+                } else {
+                    right += 1;
+                }
+            }
+        }
+        // If we get here, we found a vertical mirroring around midpoint.
+        debug!(
+            target: "day13::reflect",
+            "Found midpoint: {}\n{}",
+            midpoint,
+            render(graph, Some(midpoint), None)
+        );
+        return Some(midpoint);
+    }
+
+    trace!(target: "day13::reflect", "width: {} height: {}", width, height);
+    'check_outer: for midpoint in 1..height {
+        trace!(target: "day13::reflect", "Checking vertical midpoint: {}", midpoint);
+        let mut top = midpoint - 1;
+        let mut bottom = midpoint;
+        let mut keep_going = true;
+        while keep_going {
+            if graph.row(top) != graph.row(bottom) {
+                trace!(target: "day13::reflect", "fail: t: {} b: {}", top, bottom);
+                continue 'check_outer;
+            }
+            if top == 0 {
+                keep_going = false;
+            } else {
+                top -= 1;
+            }
+            if bottom == height - 1 {
+                keep_going = false;
+            } else {
+                bottom += 1;
+            }
+        }
+
+        debug!(
+            target: "day13::reflect",
+            "Found vertical midpoint {}\n{}",
+            midpoint,
+            render(graph, None, Some(midpoint))
+        );
+        return Some(midpoint * 100);
+    }
+
+    debug!(target: "day13::reflect", "No reflection:\n{}", render(graph, None, None));
+    None
+}
+
+// Patterns are separated by blank lines.
+fn read_patterns(lines: &[String]) -> Result<Vec<Grid<bool>>, ParseError> {
+    let mut blocks = Vec::new();
+    let mut block: Vec<Line> = Vec::new();
+    for line in numbered(lines) {
+        if line.text.is_empty() {
+            if !block.is_empty() {
+                blocks.push(std::mem::take(&mut block));
+            }
+        } else {
+            block.push(line);
+        }
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+    blocks
+        .into_iter()
+        .map(|block| {
+            Grid::parse_lines(block, |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            })
+        })
+        .collect()
+}
+
+// Random patterns with one half copied over onto the other, either side to
+// side or top to bottom.
+fn random_patterns(rng: &mut Rng, patterns: usize) -> String {
+    let mut blocks = Vec::new();
+    for _ in 0..patterns {
+        let (width, height) = (rng.range(5..18) as usize, rng.range(5..18) as usize);
+        let mut cells = Grid::from_fn(width, height, |_| rng.chance(0.5));
+        if rng.chance(0.5) {
+            let mid = rng.range(1..width as i64) as usize;
+            for y in 0..height {
+                for x in mid..width.min(2 * mid) {
+                    cells[(x, y)] = cells[(2 * mid - 1 - x, y)];
+                }
+            }
+        } else {
+            let mid = rng.range(1..height as i64) as usize;
+            for y in mid..height.min(2 * mid) {
+                for x in 0..width {
+                    cells[(x, y)] = cells[(x, 2 * mid - 1 - y)];
+                }
+            }
+        }
+        blocks.push(cells.map(|&c| if c { '#' } else { '.' }).to_string());
+    }
+    blocks.join("\n")
+}
+
+/// Sums [`find_reflection`] over every pattern.
+pub fn get_reflection_score(patterns: &[Grid<bool>]) -> Result<usize, SolveError> {
+    let mut score = 0;
+    for (i, pattern) in patterns.iter().enumerate() {
+        score += find_reflection(pattern).ok_or_else(|| {
+            SolveError::no_answer(format!("pattern {} has no line of reflection", i + 1))
+        })?;
+    }
+    Ok(score)
+}
+
+/// Day 13: Lines of reflection in patterns of ash and rocks.
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const PARTS: u8 = 1;
+
+    type Input = Vec<Grid<bool>>;
+    type Answer = Result<usize, SolveError>;
+    type Params = NoParams;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        read_patterns(lines)
+    }
+
+    fn part1(patterns: &Self::Input) -> Self::Answer {
+        get_reflection_score(patterns)
+    }
+
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new()
+            .grid("[.#]")
+            .repeat(1, Fingerprint::new().blank().grid("[.#]"))
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(random_patterns(rng, 100 * scale))
+    }
+}
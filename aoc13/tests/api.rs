@@ -0,0 +1,36 @@
+use aoc13::{find_reflection, Day13};
+use aoc_common::Solution;
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day13>();
+}
+
+#[test]
+fn test_no_reflection() {
+    let patterns = Day13::parse_str("#.#\n.##\n\n##\n..\n\n#.\n.#\n").unwrap();
+    assert_eq!(find_reflection(&patterns[0]), None);
+    assert_eq!(find_reflection(&patterns[1]), Some(1));
+    let err = Day13::part1(&patterns).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "error: no answer: pattern 1 has no line of reflection"
+    );
+}
+
+#[test]
+fn test_unsolved_part() {
+    let day = aoc_common::solver::<Day13>();
+    let text = "#.\n#.\n";
+    assert_eq!(day.solve_str(text, &[1]), Ok(vec!["100".to_string()]));
+    let err = day.solve_str(text, &[2]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "error: no answer to part 2: it isn't solved yet"
+    );
+    let err = day.solve_str(text, &[3]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "error: no answer to part 3: there's no such part"
+    );
+}
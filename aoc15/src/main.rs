@@ -1,3 +1,19 @@
-fn main() {
-    println!("Hello, world!");
+use aoc15::Day15;
+use aoc_common::{input_path, read_lines, solver};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let day = solver::<Day15>();
+    let parts: Vec<u8> = (1..=day.parts()).collect();
+    if parts.is_empty() {
+        println!("Day 15 isn't solved yet");
+        return Ok(());
+    }
+    for name in ["prelim.txt", "input.txt"] {
+        let lines = read_lines(input_path!(name))?;
+        for result in day.solve_timed(name, &lines, &parts)?.results {
+            println!("{} part {}: {}", name, result.part, result.answer);
+        }
+    }
+    Ok(())
 }
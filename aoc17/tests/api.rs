@@ -0,0 +1,18 @@
+use aoc17::Day17;
+
+#[test]
+fn test_prelim() {
+    // Nothing to load until the example has been saved.
+    let path = aoc_common::input_path!("prelim.txt");
+    if !path.exists() {
+        return;
+    }
+    if let Err(err) = aoc_common::load::<Day17>(path) {
+        panic!("{}", err);
+    }
+}
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day17>();
+}
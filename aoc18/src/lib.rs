@@ -0,0 +1,22 @@
+use aoc_common::{NoParams, ParseError, Solution, SolveError};
+
+/// Day 18, as far as it's been solved.
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    // Bump this as each part is solved, and add its answers to answers.txt.
+    const PARTS: u8 = 0;
+
+    type Input = Vec<String>;
+    type Answer = Result<u64, SolveError>;
+    type Params = NoParams;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        Ok(lines.to_vec())
+    }
+
+    fn part1(_input: &Self::Input) -> Self::Answer {
+        Err(SolveError::no_answer("it isn't solved yet"))
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_common::{Grid, NoParams, ParseError, Solution, SolveError};
+
+/// Day 23, as far as it's been solved.
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    // Bump this as each part is solved, and add its answers to answers.txt.
+    const PARTS: u8 = 0;
+
+    type Input = Grid<char>;
+    type Answer = Result<u64, SolveError>;
+    type Params = NoParams;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        Grid::parse_chars(lines)
+    }
+
+    fn part1(_input: &Self::Input) -> Self::Answer {
+        Err(SolveError::no_answer("it isn't solved yet"))
+    }
+}
@@ -0,0 +1,184 @@
+use aoc_common::params::Validate;
+use aoc_common::{numbered, Fingerprint, ParseError, Pattern, Rng, Solution, SolveError};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, trace};
+
+/// The test area that the real input's answer is counted in.
+pub const POS_MIN: f64 = 200000000000000.0;
+pub const POS_MAX: f64 = 400000000000000.0;
+
+/// The test area, in both x and y. The examples use 7 to 27.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub pos_min: f64,
+    pub pos_max: f64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            pos_min: POS_MIN,
+            pos_max: POS_MAX,
+        }
+    }
+}
+
+impl Validate for Params {
+    fn validate(&self) -> Result<(), String> {
+        if !(self.pos_min.is_finite() && self.pos_max.is_finite()) {
+            return Err("pos_min and pos_max must be finite".to_string());
+        }
+        if self.pos_min > self.pos_max {
+            return Err("pos_min can't be more than pos_max".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// A hailstone's position and velocity.
+#[derive(Clone, Copy, Debug)]
+pub struct Hail {
+    px: f64,
+    py: f64,
+    #[allow(dead_code)] // z is not needed for the 2D intersection test
+    pz: f64,
+    vx: f64,
+    vy: f64,
+    #[allow(dead_code)]
+    vz: f64,
+}
+
+impl Hail {
+    fn new(px: f64, py: f64, pz: f64, vx: f64, vy: f64, vz: f64) -> Self {
+        Self {
+            px,
+            py,
+            pz,
+            vx,
+            vy,
+            vz,
+        }
+    }
+
+    fn parallel(self, other: &Hail) -> bool {
+        if self.vx == other.vx && self.vy == other.vy {
+            return true;
+        }
+        let left = self.vy * other.vx;
+        let right = other.vy * self.vx;
+        left == right
+    }
+
+    /// Whether the two paths cross inside the test area in x and y, ignoring z
+    /// and only looking forward in time.
+    pub fn intersects(self, other: &Hail, pos_min: f64, pos_max: f64) -> bool {
+        // https://stackoverflow.com/questions/73079419/intersection-of-two-vector
+        if self.parallel(other) {
+            trace!(target: "day24::intersect", "parallel {:?} {:?}", self, other);
+            return false;
+        }
+
+        // I couldn't remember all my linear algebra.
+        // https://stackoverflow.com/questions/2931573/determining-if-two-rays-intersect
+        let dx = other.px - self.px;
+        let dy = other.py - self.py;
+        let det = other.vx * self.vy - other.vy * self.vx;
+        let u = (dy * other.vx - dx * other.vy) / det;
+        let v = (dy * self.vx - dx * self.vy) / det;
+        // The times need to be in the future, and not in the past.
+        if u >= 0.0 && v >= 0.0 {
+            trace!(target: "day24::intersect", "intersection between {:?} {:?}", self, other);
+            trace!(target: "day24::intersect", "u: {}, v:{}", u, v);
+            let new_x = self.px + self.vx * u;
+            let new_y = self.py + self.vy * u;
+            if new_x >= pos_min && new_x <= pos_max && new_y >= pos_min && new_y <= pos_max {
+                trace!(target: "day24::intersect", "new_x: {}, new_y:{}", new_x, new_y);
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+const HAIL: Pattern = Pattern::new("{px}, {py}, {pz} @ {vx}, {vy}, {vz}");
+
+fn read_hail(lines: &[String]) -> Result<Vec<Hail>, ParseError> {
+    let mut hail = Vec::new();
+    for line in numbered(lines) {
+        let caps = HAIL.parse_line(line)?;
+        hail.push(Hail::new(
+            caps.get("px")?,
+            caps.get("py")?,
+            caps.get("pz")?,
+            caps.get("vx")?,
+            caps.get("vy")?,
+            caps.get("vz")?,
+        ));
+    }
+
+    debug!(target: "day24::parse", "hail: {:?}", hail);
+    Ok(hail)
+}
+
+// As in the real puzzle there's a rock that hits every hailstone: each one is
+// wound back from where the rock meets it at some random time.
+fn random_hail(rng: &mut Rng, stones: usize) -> String {
+    let rock: Vec<i64> = (0..3)
+        .map(|_| rng.range(POS_MIN as i64..POS_MAX as i64))
+        .collect();
+    let rock_v: Vec<i64> = (0..3).map(|_| rng.range(-250..251)).collect();
+    let mut text = String::new();
+    for _ in 0..stones {
+        let t = rng.range(100_000_000_000..300_000_000_000);
+        let v: Vec<i64> = (0..3).map(|_| rng.range(-300..301)).collect();
+        let p: Vec<i64> = (0..3).map(|i| rock[i] + (rock_v[i] - v[i]) * t).collect();
+        text += &HAIL.render(&[&p[0], &p[1], &p[2], &v[0], &v[1], &v[2]]);
+        text.push('\n');
+    }
+    text
+}
+
+/// How many pairs of paths cross inside the test area.
+pub fn get_intersections(hail: &[Hail], pos_min: f64, pos_max: f64) -> usize {
+    let mut intersections = 0;
+    for x in 0..hail.len() {
+        for y in x + 1..hail.len() {
+            if hail[x].intersects(&hail[y], pos_min, pos_max) {
+                intersections += 1;
+            }
+        }
+    }
+
+    intersections
+}
+
+/// Day 24: Hailstones whose paths cross in the test area.
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const PARTS: u8 = 1;
+
+    type Input = Vec<Hail>;
+    type Answer = Result<usize, SolveError>;
+    type Params = Params;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        read_hail(lines)
+    }
+
+    fn part1(hail: &Self::Input) -> Self::Answer {
+        let params = Self::params();
+        Ok(get_intersections(hail, params.pos_min, params.pos_max))
+    }
+
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new().lines(r"-?\d+, +-?\d+, +-?\d+ +@ +-?\d+, +-?\d+, +-?\d+")
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(random_hail(rng, 300 * scale))
+    }
+}
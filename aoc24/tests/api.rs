@@ -0,0 +1,27 @@
+use aoc24::{get_intersections, Day24};
+use aoc_common::{input_path, read_lines, Solution};
+
+#[test]
+fn test_prelim() {
+    let lines = read_lines(input_path!("prelim.txt")).unwrap();
+    let intersections = get_intersections(&Day24::parse(&lines).unwrap(), 7.0, 27.0);
+    assert_eq!(intersections, 2);
+}
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day24>();
+}
+
+#[test]
+fn test_bad_params() {
+    let day = aoc_common::solver::<Day24>();
+    let values = aoc_common::params::Table::from_iter([
+        ("pos_min".to_string(), 27.0.into()),
+        ("pos_max".to_string(), 7.0.into()),
+    ]);
+    assert_eq!(
+        day.check_params(&values).unwrap_err(),
+        "pos_min can't be more than pos_max"
+    );
+}
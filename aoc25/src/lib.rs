@@ -0,0 +1,155 @@
+use rustworkx_core::connectivity::stoer_wagner_min_cut;
+use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+use rustworkx_core::Result;
+
+use aoc_common::graph::Interner;
+use aoc_common::{numbered, Fingerprint, NoParams, ParseError, Rng, Solution, SolveError};
+use std::collections::{BTreeMap, HashSet};
+use tracing::debug;
+
+/// The components and the wires between them.
+pub struct MyGraph {
+    names: Interner,
+    graph: UnGraph<String, usize>,
+}
+
+impl MyGraph {
+    fn new() -> Self {
+        let graph = UnGraph::new_undirected();
+        MyGraph {
+            names: Interner::new(),
+            graph,
+        }
+    }
+
+    // Interned ids are handed out in order, just like petgraph's indices.
+    fn get_node(&mut self, name: &str) -> NodeIndex {
+        let id = self.names.intern(name);
+        if id == self.graph.node_count() {
+            self.graph.add_node(name.to_string());
+        }
+        NodeIndex::new(id)
+    }
+}
+
+fn read_graph(lines: &[String]) -> Result<MyGraph, ParseError> {
+    let mut graph = MyGraph::new();
+
+    for line in numbered(lines) {
+        let (lhs, rhs) = line.split_once(line.text, ": ")?;
+        let rtoks: Vec<_> = rhs.split_whitespace().collect();
+        if lhs.is_empty() || lhs.contains(char::is_whitespace) {
+            return Err(line.error(lhs, "expected a component name"));
+        }
+
+        let l = graph.get_node(lhs);
+        for rhs in rtoks {
+            let r = graph.get_node(rhs);
+            graph.graph.add_edge(l, r, 1);
+        }
+    }
+
+    Ok(graph)
+}
+
+// Two well connected halves joined by exactly three wires. Each half is a ring
+// with a few random chords from every component, which makes cutting it
+// anywhere cost more than three.
+fn random_wiring(rng: &mut Rng, components: usize) -> String {
+    let letters = if components <= 26usize.pow(3) { 3 } else { 4 };
+    let mut names = HashSet::new();
+    while names.len() < components {
+        let name: String = (0..letters)
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect();
+        names.insert(name);
+    }
+    let mut names: Vec<String> = names.into_iter().collect();
+    names.sort();
+    rng.shuffle(&mut names);
+
+    let split = components * (3 + rng.index(5)) / 10;
+    let mut wires = HashSet::new();
+    for half in [0..split, split..components] {
+        let (start, len) = (half.start, half.len());
+        for i in 0..len {
+            wires.insert((start + i, start + (i + 1) % len));
+            for _ in 0..3 {
+                let j = rng.index(len);
+                if j != i && !wires.contains(&(start + j, start + i)) {
+                    wires.insert((start + i, start + j));
+                }
+            }
+        }
+    }
+    let mut cut = HashSet::new();
+    while cut.len() < 3 {
+        cut.insert((rng.index(split), split + rng.index(components - split)));
+    }
+    wires.extend(cut);
+
+    let mut lines: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+    let mut wires: Vec<(usize, usize)> = wires.into_iter().collect();
+    wires.sort();
+    for (a, b) in wires {
+        let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+        lines.entry(a).or_default().push(&names[b]);
+    }
+    let mut lines: Vec<String> = lines
+        .into_iter()
+        .map(|(a, others)| format!("{}: {}", names[a], others.join(" ")))
+        .collect();
+    rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
+}
+
+/// Cuts the three wires that split the components in two and multiplies
+/// the sizes of the two groups.
+pub fn compute_sizes(graph: &MyGraph) -> Result<usize, SolveError> {
+    let node_count = graph.graph.node_count();
+
+    let min_cut_res: Result<Option<(usize, Vec<_>)>> =
+        stoer_wagner_min_cut(&graph.graph, |_| Ok(1));
+
+    let Ok(min_cut_res) = min_cut_res;
+    let (min_cut, partition) =
+        min_cut_res.ok_or_else(|| SolveError::no_answer("there's only one component"))?;
+
+    debug!(target: "day25::cut", "min_cut: {:?} partition: {:?}", min_cut, partition);
+
+    if min_cut != 3 {
+        let reason = format!("the fewest wires that split it are {}, not 3", min_cut);
+        return Err(SolveError::no_answer(reason));
+    }
+    let left = partition.len();
+    let right = node_count - left;
+    Ok(left * right)
+}
+
+/// Day 25: The three wires that split the components in two.
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const PARTS: u8 = 1;
+
+    type Input = MyGraph;
+    type Answer = Result<usize, SolveError>;
+    type Params = NoParams;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        read_graph(lines)
+    }
+
+    fn part1(graph: &Self::Input) -> Self::Answer {
+        compute_sizes(graph)
+    }
+
+    fn fingerprint() -> Fingerprint {
+        Fingerprint::new().lines("[a-z]+:( [a-z]+)+")
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(random_wiring(rng, 1500 * scale))
+    }
+}
@@ -0,0 +1,19 @@
+use aoc25::Day25;
+use aoc_common::Solution;
+
+#[test]
+fn test_answers() {
+    aoc_common::check_answers::<Day25>();
+}
+
+#[test]
+fn test_no_three_wire_cut() {
+    let graph = Day25::parse_str("aaa: bbb\nbbb: ccc\n").unwrap();
+    let err = Day25::part1(&graph).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "error: no answer: the fewest wires that split it are 1, not 3"
+    );
+    let graph = Day25::parse_str("aaa: aaa\n").unwrap();
+    assert!(Day25::part1(&graph).is_err());
+}
@@ -0,0 +1,4 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| aoc_fuzz::solve::<aoc05::Day05>(text));
@@ -0,0 +1,4 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (u8, u64)| aoc_fuzz::solve_generated(input.0, input.1));
@@ -0,0 +1,26 @@
+use aoc_common::{solver, Solution};
+use aoc_days::all_days;
+
+// No input may panic. Whatever the parser accepts goes on to every part, and
+// either it's turned away or a part reports that it has no answer, with an
+// error that has to be printable.
+pub fn solve<S: Solution + 'static>(text: &str) {
+    let day = solver::<S>();
+    let parts: Vec<u8> = (1..=day.parts()).collect();
+    if let Err(err) = day.solve_str(text, &parts) {
+        let _ = err.to_string();
+    }
+}
+
+// The generators' inputs are meant to be real puzzles, so they have to be
+// solved outright, without even an error.
+pub fn solve_generated(day: u8, seed: u64) {
+    let days = all_days();
+    let day = &days[day as usize % days.len()];
+    if let Some(text) = day.generate(seed, 1) {
+        let parts: Vec<u8> = (1..=day.parts()).collect();
+        if let Err(err) = day.solve_str(&text, &parts) {
+            panic!("day {} seed {}: {}", day.day(), seed, err);
+        }
+    }
+}